@@ -1,5 +1,5 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use seq_chunking::SeqChunking;
+use seq_chunking::{ChunkingConfig, SeqChunker, SeqChunking};
 use seq_chunking::utils::TestDataGenerator;
 use std::fs::File;
 use std::io::Write;
@@ -26,5 +26,39 @@ fn chunking_benchmark(c: &mut Criterion) {
     std::fs::remove_file(&file_path).unwrap();
 }
 
-criterion_group!(benches, chunking_benchmark);
+fn ramp_benchmark(c: &mut Criterion) {
+    let chunker = SeqChunker::<u32>::new();
+    let data: Vec<u32> = (0..16_000_000).collect();
+
+    c.bench_function("chunking 16M-element ramp", |b| {
+        b.iter(|| chunker.chunk_offsets(&data))
+    });
+}
+
+fn zero_region_benchmark(c: &mut Criterion) {
+    let chunker = SeqChunking::new();
+    let data = vec![0u8; 256_000_000];
+
+    c.bench_function("chunking 256MB zero region", |b| {
+        b.iter(|| chunker.chunk_offsets(&data))
+    });
+}
+
+fn small_chunks_benchmark(c: &mut Criterion) {
+    let config = ChunkingConfig::builder()
+        .min_block_size(32)
+        .avg_block_size(64)
+        .max_block_size(128)
+        .build()
+        .unwrap();
+    let chunker = SeqChunking::from_config(config);
+    let data = TestDataGenerator::generate_pseudo_random(256_000_000, 54321);
+
+    // About two million chunks, so per-chunk overhead dominates
+    c.bench_function("chunking 256MB into small chunks", |b| {
+        b.iter(|| chunker.chunk_all(&data).count())
+    });
+}
+
+criterion_group!(benches, chunking_benchmark, ramp_benchmark, zero_region_benchmark, small_chunks_benchmark);
 criterion_main!(benches);
\ No newline at end of file
@@ -0,0 +1,107 @@
+//! Command-line interface for chunking files.
+
+use clap::{Parser, ValueEnum};
+use seq_chunking::hashing::hash_bytes;
+use seq_chunking::utils::FileUtils;
+use seq_chunking::{ChunkingConfig, SeqChunking, SeqOpMode};
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// Sequence detection mode
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Mode {
+    Increasing,
+    Decreasing,
+}
+
+impl From<Mode> for SeqOpMode {
+    fn from(mode: Mode) -> Self {
+        match mode {
+            Mode::Increasing => SeqOpMode::Increasing,
+            Mode::Decreasing => SeqOpMode::Decreasing,
+        }
+    }
+}
+
+/// Chunk a file with SeqCDC and print each chunk as TSV (offset, length, SHA-256)
+#[derive(Debug, Parser)]
+#[command(name = "seqcdc", version)]
+struct Args {
+    /// File to chunk
+    path: PathBuf,
+
+    /// Minimum chunk size in bytes
+    #[arg(long, default_value_t = seq_chunking::DEFAULT_MIN_BLOCK_SIZE)]
+    min: u64,
+
+    /// Maximum chunk size in bytes
+    #[arg(long, default_value_t = seq_chunking::DEFAULT_MAX_BLOCK_SIZE)]
+    max: u64,
+
+    /// Target average chunk size in bytes (defaults to a value within min..=max)
+    #[arg(long)]
+    avg: Option<u64>,
+
+    /// Number of consecutive sequence bytes needed to trigger a cut
+    #[arg(long, default_value_t = seq_chunking::DEFAULT_SEQ_THRESHOLD)]
+    seq_threshold: u64,
+
+    /// Sequence detection mode
+    #[arg(long, value_enum, default_value_t = Mode::Increasing)]
+    mode: Mode,
+
+    /// Print chunking statistics instead of individual chunks
+    #[arg(long)]
+    stats: bool,
+}
+
+fn run(args: Args) -> seq_chunking::Result<()> {
+    let mut builder = ChunkingConfig::builder()
+        .min_block_size(args.min)
+        .max_block_size(args.max)
+        .seq_threshold(args.seq_threshold)
+        .op_mode(args.mode.into());
+    if let Some(avg) = args.avg {
+        builder = builder.avg_block_size(avg);
+    }
+    let config = builder.build()?;
+    let chunker = SeqChunking::from_config(config);
+    let data = FileUtils::read_file_buffered(&args.path)?;
+
+    let mut out = BufWriter::new(std::io::stdout().lock());
+
+    if args.stats {
+        let stats = chunker.stats(&data);
+        writeln!(out, "chunk_count\t{}", stats.chunk_count)?;
+        writeln!(out, "total_size\t{}", stats.total_size)?;
+        writeln!(out, "avg_chunk_size\t{:.1}", stats.avg_chunk_size)?;
+        writeln!(out, "min_chunk_size\t{}", stats.min_chunk_size)?;
+        writeln!(out, "max_chunk_size\t{}", stats.max_chunk_size)?;
+        writeln!(out, "chunk_size_stddev\t{:.1}", stats.chunk_size_stddev)?;
+        writeln!(out, "median_chunk_size\t{}", stats.median_chunk_size)?;
+        writeln!(out, "p90_chunk_size\t{}", stats.p90_chunk_size)?;
+        writeln!(out, "p99_chunk_size\t{}", stats.p99_chunk_size)?;
+    } else {
+        for chunk in chunker.chunk_all(&data) {
+            let hash: String = hash_bytes(chunk.data)
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect();
+            writeln!(out, "{}\t{}\t{}", chunk.start, chunk.len, hash)?;
+        }
+    }
+
+    out.flush()?;
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run(Args::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("seqcdc: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
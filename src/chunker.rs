@@ -1,22 +1,194 @@
 //! Core chunking implementation.
 
-use crate::config::{ChunkingConfig, SeqOpMode};
-use crate::error::Result;
+use crate::config::{ChunkingConfig, CutStrategy, FallbackPolicy, SeqOpMode, SlopeTransform, SmallInputPolicy};
+use crate::element::SeqElement;
+use crate::hasher::ChunkHasher;
+use crate::error::{ChunkingError, Result};
+use crate::utils::ValidationUtils;
+#[cfg(feature = "hashing")]
+use crate::hashing::{HASH_SIZE, hash_bytes};
+use crate::{ALGORITHM_VERSION, FAST_FIRST_CHUNK_MIN_SIZE};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+#[cfg(feature = "bytes")]
+use bytes::Bytes;
+use core::marker::PhantomData;
+use core::ops::Range;
+#[cfg(feature = "std")]
+use std::sync::{Arc, mpsc::Sender};
+
+/// Number of scanned positions before `max_absorption_ratio` is enforced
+const MIN_ABSORPTION_SAMPLE: u64 = 64;
+
+/// Maximum number of trial chunkings run by `calibrate`
+const CALIBRATION_MAX_TRIALS: usize = 24;
+
+/// Largest `seq_threshold` tried by `calibrate`
+const CALIBRATION_MAX_THRESHOLD: u64 = 32;
+
+/// Percentile of run lengths returned by `suggest_seq_threshold`
+const SUGGESTED_THRESHOLD_PERCENTILE: u64 = 95;
+
+/// Maximum number of block size rescalings tried by `chunk_into_n`
+const CHUNK_INTO_N_ROUNDS: usize = 4;
+
+/// Slopes classified per block by the `simd` fast path
+#[cfg(feature = "simd")]
+const BLOCK_LANES: usize = 16;
+
+/// Gear hash table, filled from a fixed SplitMix64 seed
+const GEAR_TABLE: [u64; 256] = gear_table(0x5eed_cdc0_9ea2_0001);
+
+const fn gear_table(seed: u64) -> [u64; 256] {
+    let mut table = [0u64; 256];
+    let mut state = seed;
+    let mut i = 0;
+    while i < 256 {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        table[i] = z ^ (z >> 31);
+        i += 1;
+    }
+    table
+}
+
+/// CRC-32/ISO-HDLC table for the reflected polynomial 0xEDB88320
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+/// CRC-32/ISO-HDLC (the zlib, PNG and Ethernet CRC) of `data`
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0u32, |crc, &byte| {
+        CRC32_TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
 
 /// Represents a single chunk of data
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Chunk<'a> {
+pub struct Chunk<'a, T = u8> {
     /// The chunk data
-    pub data: &'a [u8],
+    pub data: &'a [T],
     /// Starting position in the original data
     pub start: usize,
     /// Length of the chunk
     pub len: usize,
 }
 
-impl<'a> Chunk<'a> {
+impl<'a, T> Chunk<'a, T> {
     /// Create a new chunk
-    pub fn new(data: &'a [u8], start: usize, len: usize) -> Self {
+    pub fn new(data: &'a [T], start: usize, len: usize) -> Self {
+        Self { data, start, len }
+    }
+
+    /// Get the end position of this chunk
+    pub fn end(&self) -> usize {
+        self.start + self.len
+    }
+
+    /// Get the position range of this chunk in the original data
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end()
+    }
+
+    /// Check if this chunk is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Chunk<'_> {
+    /// CRC-32/ISO-HDLC checksum of the chunk, as computed by zlib's `crc32`
+    ///
+    /// Matches Python's `zlib.crc32` and Go's `crc32.ChecksumIEEE`, so a
+    /// receiver in another language can verify chunks in transit.
+    pub fn crc32(&self) -> u32 {
+        crc32(self.data)
+    }
+
+    /// Compute the zstd-compressed size of this chunk at the given level
+    ///
+    /// The compressed output is discarded; only its length is kept. Falls back
+    /// to the uncompressed length if the encoder fails.
+    #[cfg(feature = "zstd")]
+    pub fn compressed_size(&self, level: i32) -> usize {
+        let mut counter = ByteCounter(0);
+        match zstd::stream::copy_encode(self.data, &mut counter, level) {
+            Ok(()) => counter.0,
+            Err(_) => self.len,
+        }
+    }
+
+    /// Shannon entropy of the chunk's bytes in bits per byte (0.0 to 8.0)
+    ///
+    /// A cheap compressibility estimate: low-entropy chunks compress well.
+    /// Empty chunks report 0.0.
+    #[cfg(feature = "std")]
+    pub fn entropy(&self) -> f64 {
+        let mut counts = [0u64; 256];
+        for &byte in self.data {
+            counts[byte as usize] += 1;
+        }
+        entropy_from_counts(&counts, self.data.len() as u64)
+    }
+
+    /// Copy this chunk into an [`OwnedChunk`]
+    pub fn to_owned_chunk(&self) -> OwnedChunk {
+        OwnedChunk::new(self.data.to_vec(), self.start)
+    }
+}
+
+/// Writer that discards its input and counts the bytes written
+#[cfg(feature = "zstd")]
+struct ByteCounter(usize);
+
+#[cfg(feature = "zstd")]
+impl std::io::Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A chunk that owns its data, detached from the original buffer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedChunk {
+    /// The chunk data
+    pub data: Vec<u8>,
+    /// Starting position in the original data
+    ///
+    /// Chunks produced incrementally ([`StreamingChunker`](crate::StreamingChunker),
+    /// `chunk_lines`) carry their absolute position in the whole stream.
+    pub start: usize,
+    /// Length of the chunk
+    pub len: usize,
+}
+
+impl OwnedChunk {
+    /// Create a new owned chunk
+    pub fn new(data: Vec<u8>, start: usize) -> Self {
+        let len = data.len();
         Self { data, start, len }
     }
 
@@ -25,31 +197,95 @@ impl<'a> Chunk<'a> {
         self.start + self.len
     }
 
+    /// Get the position range of this chunk in the original data
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end()
+    }
+
     /// Check if this chunk is empty
     pub fn is_empty(&self) -> bool {
         self.len == 0
     }
+
+    /// Borrow this chunk as a [`Chunk`]
+    pub fn as_chunk(&self) -> Chunk<'_> {
+        Chunk::new(&self.data, self.start, self.len)
+    }
+
+    /// Compute the SHA-256 hash of the chunk data
+    #[cfg(feature = "hashing")]
+    pub fn hash(&self) -> [u8; HASH_SIZE] {
+        hash_bytes(&self.data)
+    }
+}
+
+/// A chunk sharing the allocation of the [`Bytes`] buffer it was cut from (`bytes` feature)
+#[cfg(feature = "bytes")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BytesChunk {
+    /// The chunk data, a slice of the chunked buffer
+    pub data: Bytes,
+    /// Starting position in the original data
+    pub start: usize,
+    /// Length of the chunk
+    pub len: usize,
+}
+
+#[cfg(feature = "bytes")]
+impl BytesChunk {
+    /// Get the end position of this chunk
+    pub fn end(&self) -> usize {
+        self.start + self.len
+    }
+
+    /// Get the position range of this chunk in the original data
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end()
+    }
+
+    /// Borrow this chunk as a [`Chunk`]
+    pub fn as_chunk(&self) -> Chunk<'_> {
+        Chunk::new(&self.data, self.start, self.len)
+    }
+}
+
+impl From<Chunk<'_>> for OwnedChunk {
+    fn from(chunk: Chunk<'_>) -> Self {
+        chunk.to_owned_chunk()
+    }
 }
 
 /// Iterator over chunks produced by the chunking algorithm
-pub struct ChunkIterator<'a> {
-    data: &'a [u8],
-    chunker: &'a SeqChunking,
+pub struct ChunkIterator<'a, T: SeqElement = u8> {
+    data: &'a [T],
+    chunker: &'a SeqChunker<T>,
     position: usize,
+    base_offset: usize,
+    prev_len: Option<usize>,
+    /// Identical elements known to follow `position`
+    run_left: usize,
 }
 
-impl<'a> ChunkIterator<'a> {
-    fn new(data: &'a [u8], chunker: &'a SeqChunking) -> Self {
+impl<'a, T: SeqElement> ChunkIterator<'a, T> {
+    fn new(data: &'a [T], chunker: &'a SeqChunker<T>) -> Self {
+        Self::starting_at(data, chunker, 0)
+    }
+
+    /// Create an iterator that starts chunking at the given position
+    pub(crate) fn starting_at(data: &'a [T], chunker: &'a SeqChunker<T>, position: usize) -> Self {
         Self {
             data,
             chunker,
-            position: 0,
+            position,
+            base_offset: 0,
+            prev_len: None,
+            run_left: 0,
         }
     }
 }
 
-impl<'a> Iterator for ChunkIterator<'a> {
-    type Item = Chunk<'a>;
+impl<'a, T: SeqElement> Iterator for ChunkIterator<'a, T> {
+    type Item = Chunk<'a, T>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.position >= self.data.len() {
@@ -57,41 +293,108 @@ impl<'a> Iterator for ChunkIterator<'a> {
         }
 
         let remaining = &self.data[self.position..];
-        let cutpoint = self
-            .chunker
-            .find_cutpoint(remaining, remaining.len() as u64);
-        let chunk_size = (cutpoint as usize).min(remaining.len());
+        let stream_position = self.base_offset + self.position;
+
+        // Inside a long run of one element the cut is known without scanning
+        let window = remaining.len().min(self.chunker.config.max_block_size as usize);
+        if self.run_left < window {
+            self.run_left = self.chunker.uniform_run(remaining, stream_position);
+        }
+        let chunk_size = if self.run_left >= window {
+            self.chunker.uniform_chunk_len(remaining.len(), stream_position, self.prev_len)
+        } else {
+            self.chunker
+                .next_chunk_len(remaining, stream_position, self.prev_len, &mut ())
+        };
+        self.run_left = self.run_left.saturating_sub(chunk_size);
 
         if chunk_size == 0 {
             return None;
         }
 
         let chunk_data = &remaining[..chunk_size];
-        let chunk = Chunk::new(chunk_data, self.position, chunk_size);
+        let chunk = Chunk::new(chunk_data, stream_position, chunk_size);
 
         self.position += chunk_size;
+        self.prev_len = Some(chunk_size);
         Some(chunk)
     }
+
+    /// Bounds the chunk count by the block size limits
+    ///
+    /// Every chunk but the last is at least `min_block_size` long, except a
+    /// `fast_first_chunk`, and none exceeds `max_block_size` unless the
+    /// `FullRemaining` fallback lets the last chunk take everything.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.data.len().saturating_sub(self.position);
+        if remaining == 0 {
+            return (0, Some(0));
+        }
+
+        let config = &self.chunker.config;
+        let lower = match config.fallback {
+            FallbackPolicy::FullRemaining => 1,
+            _ => remaining.div_ceil(config.max_block_size.max(1) as usize),
+        };
+        let fast_first = config.fast_first_chunk && self.base_offset + self.position == 0;
+        let upper = remaining / config.min_block_size.max(1) as usize + 1 + fast_first as usize;
+        (lower, Some(upper))
+    }
+}
+
+/// Byte chunking algorithm, usable as `&dyn Chunker` to swap implementations
+pub trait Chunker {
+    /// Length of the chunk at the start of `buff`, looking at most `size` bytes ahead
+    fn find_cutpoint(&self, buff: &[u8], size: u64) -> u64;
+
+    /// Iterate over all chunks of `data`
+    fn chunk_all<'a>(&'a self, data: &'a [u8]) -> Box<dyn Iterator<Item = Chunk<'a>> + 'a>;
+}
+
+impl Chunker for SeqChunking {
+    fn find_cutpoint(&self, buff: &[u8], size: u64) -> u64 {
+        SeqChunking::find_cutpoint(self, buff, size)
+    }
+
+    fn chunk_all<'a>(&'a self, data: &'a [u8]) -> Box<dyn Iterator<Item = Chunk<'a>> + 'a> {
+        Box::new(SeqChunking::chunk_all(self, data))
+    }
 }
 
-/// Main chunking algorithm implementation
+/// Main chunking algorithm implementation, generic over the element type
+///
+/// Block sizes and positions are counted in elements, so for `u16` streams a
+/// `min_block_size` of 4096 means 4096 samples.
 #[derive(Debug, Clone)]
-pub struct SeqChunking {
+pub struct SeqChunker<T: SeqElement = u8> {
     config: ChunkingConfig,
-    technique_name: String,
+    technique_name: &'static str,
+    /// Whether the `simd` block fast path is used (always, outside tests)
+    #[cfg(feature = "simd")]
+    block_scan: bool,
+    _element: PhantomData<T>,
 }
 
-impl SeqChunking {
+/// Byte-oriented chunker
+pub type SeqChunking = SeqChunker<u8>;
+
+impl<T: SeqElement> SeqChunker<T> {
     /// Create a new chunker with default configuration
     pub fn new() -> Self {
         Self::from_config(ChunkingConfig::new())
     }
 
     /// Create a new chunker with the given configuration
-    pub fn from_config(config: ChunkingConfig) -> Self {
+    ///
+    /// This is a `const fn`, so together with [`ChunkingConfig::const_new`] a
+    /// chunker can be built at compile time.
+    pub const fn from_config(config: ChunkingConfig) -> Self {
         Self {
             config,
-            technique_name: "Seq Chunking".to_string(),
+            technique_name: "Seq Chunking",
+            #[cfg(feature = "simd")]
+            block_scan: true,
+            _element: PhantomData,
         }
     }
 
@@ -102,8 +405,8 @@ impl SeqChunking {
     }
 
     /// Get the technique name
-    pub fn technique_name(&self) -> &str {
-        &self.technique_name
+    pub fn technique_name(&self) -> &'static str {
+        self.technique_name
     }
 
     /// Get the minimum block size
@@ -121,298 +424,3594 @@ impl SeqChunking {
         &self.config
     }
 
-    /// Find the cutpoint for increasing sequences
-    fn find_cutpoint_increasing(&self, buff: &[u8], size: u64) -> u64 {
-        let mut curr_pos = self.config.min_block_size as usize;
-        let mut opposing_slope_count: u64 = 0;
-        let mut curr_seq_length: u64 = 0;
-        let size_usize = size as usize;
+    /// Number of preceding elements a single slope comparison reads
+    ///
+    /// This is `slope_window` times the order of the transform: one window for
+    /// `Raw`, two for `FirstDiff` and three for `SecondDiff`. Slopes never read
+    /// before the start of the current chunk, so this is also the number of
+    /// trailing elements a buffered caller must keep ahead of a scan position.
+    pub fn required_lookback(&self) -> usize {
+        let order = match self.config.transform {
+            SlopeTransform::Raw => 1,
+            SlopeTransform::FirstDiff => 2,
+            SlopeTransform::SecondDiff => 3,
+        };
+        order * self.config.slope_window as usize
+    }
 
-        while curr_pos < size_usize && curr_pos < buff.len() && curr_pos > 0 {
-            let cmp_result = buff[curr_pos] as i16 - buff[curr_pos - 1] as i16;
+    /// Effective sequence thresholds before and after `avg_block_size`
+    ///
+    /// With normalization enabled, cuts are harder to trigger before the
+    /// average block size and easier after it, pulling chunk sizes towards
+    /// the average. A configured `seq_threshold_relaxed` replaces the
+    /// threshold after the average.
+    fn normalized_thresholds(&self) -> (u64, u64) {
+        let level = self.config.normalization_level as u64;
+        let threshold = self.config.seq_threshold;
+        let relaxed = self
+            .config
+            .seq_threshold_relaxed
+            .unwrap_or_else(|| threshold.saturating_sub(level).max(1));
+        (threshold + level, relaxed)
+    }
 
-            // Low Entropy Absorption - skip equal bytes
-            if cmp_result == 0 {
-                curr_pos += 1;
-                continue;
-            }
+    /// Difference between an element and its predecessor, wrapped if configured
+    #[inline]
+    fn element_diff(&self, curr: i64, prev: i64) -> i64 {
+        if self.config.wrapping_diff {
+            T::wrap_diff(curr - prev)
+        } else {
+            curr - prev
+        }
+    }
 
-            let cmp_sign = cmp_result < 0;
+    /// Slope at `pos` in the configured transform domain
+    ///
+    /// Elements are compared `slope_window` positions apart. Positions without
+    /// enough predecessors for the window and transform report a flat slope,
+    /// so they are absorbed like equal bytes.
+    #[inline]
+    fn slope(&self, buff: &[T], pos: usize) -> i64 {
+        if pos < self.required_lookback() {
+            return 0;
+        }
 
-            if cmp_sign {
-                opposing_slope_count += 1;
-                curr_seq_length = 0;
-            } else {
-                curr_seq_length += 1;
-            }
+        let w = self.config.slope_window as usize;
+        let x = |steps: usize| buff[pos - steps * w].to_i64();
+        let d = |steps: usize| self.element_diff(x(steps), x(steps + 1));
+        match self.config.transform {
+            SlopeTransform::Raw => d(0),
+            SlopeTransform::FirstDiff => d(0) - d(1),
+            SlopeTransform::SecondDiff => d(0) - 2 * d(1) + d(2),
+        }
+    }
+
+    /// Move a cut so the chunk stays within `max_size_ratio` of the previous one
+    ///
+    /// The adjusted length is kept within the block size bounds and
+    /// `remaining`, so the ratio can only be exceeded where those bounds or
+    /// the end of the data leave no room.
+    pub(crate) fn smooth_len(&self, len: usize, prev_len: Option<usize>, remaining: usize) -> usize {
+        let (Some(ratio), Some(prev)) = (self.config.max_size_ratio, prev_len) else {
+            return len;
+        };
+
+        let upper = ((prev as f64 * ratio) as usize).max(self.config.min_block_size as usize);
+        // Only a `FullRemaining` final chunk left whole may exceed `max_block_size`
+        let upper = if len == remaining && len <= upper {
+            upper
+        } else {
+            upper.min(self.config.max_block_size as usize)
+        };
+        // Round up without `f64::ceil`, which needs `std`
+        let quotient = prev as f64 / ratio;
+        let lower = quotient as usize + ((quotient as usize as f64) < quotient) as usize;
+        let lower = lower.min(self.config.max_block_size as usize);
+        len.min(upper).max(lower).min(remaining)
+    }
+
+    /// Whether the share of absorbed equal-byte positions calls for a forced cut
+    #[inline]
+    fn absorption_exceeded(&self, absorbed: u64, scanned: u64) -> bool {
+        match self.config.max_absorption_ratio {
+            Some(ratio) if scanned >= MIN_ABSORPTION_SAMPLE => absorbed as f32 > ratio * scanned as f32,
+            _ => false,
+        }
+    }
+
+    /// Classify the next `BLOCK_LANES` slopes at once and skip them if they
+    /// cannot end the scan
+    ///
+    /// Returns the opposing slopes, the sequence length after the block and
+    /// the absorbed positions, or `None` when the block could cut, jump or
+    /// cross a threshold change, leaving it to the scalar loop. Skipping is
+    /// exact, so boundaries are identical to a byte-at-a-time scan.
+    #[cfg(feature = "simd")]
+    #[inline]
+    fn skip_block(&self, buff: &[T], pos: u64, scan_end: u64, rising: bool, seq_length: u64, opposing: u64) -> Option<(u64, u64, u64)> {
+        let avg_pos = self.config.avg_block_size;
+        let end = pos + BLOCK_LANES as u64;
+        if !self.block_scan || end > scan_end || (pos < avg_pos && end > avg_pos) || self.config.max_absorption_ratio.is_some()
+            || self.config.alternation_threshold.is_some()
+            || !self.config.absorb_equal_bytes
+            || (self.config.window_step && self.config.slope_window > 1)
+        {
+            return None;
+        }
 
-            if curr_seq_length >= self.config.seq_threshold {
-                return curr_pos as u64;
+        let (strict_threshold, lenient_threshold) = self.normalized_thresholds();
+        let threshold = if pos < avg_pos { strict_threshold } else { lenient_threshold };
+        let pos = pos as usize;
+
+        // Raw unit-window slopes are plain neighbour comparisons, which the
+        // compiler vectorizes; other transforms go through `slope`
+        let (mut up, mut down) = (0u32, 0u32);
+        if self.config.transform == SlopeTransform::Raw && self.config.slope_window == 1 && !self.config.wrapping_diff {
+            let window = &buff[pos - 1..pos + BLOCK_LANES];
+            for lane in 0..BLOCK_LANES {
+                let (prev, curr) = (window[lane].to_i64(), window[lane + 1].to_i64());
+                up |= ((curr > prev) as u32) << lane;
+                down |= ((curr < prev) as u32) << lane;
             }
+        } else {
+            for lane in 0..BLOCK_LANES {
+                let slope = self.slope(buff, pos + lane);
+                up |= ((slope > 0) as u32) << lane;
+                down |= ((slope < 0) as u32) << lane;
+            }
+        }
+        let (along, against) = if rising { (up, down) } else { (down, up) };
 
-            if opposing_slope_count >= self.config.jump_trigger {
-                curr_pos += self.config.jump_size as usize;
-                opposing_slope_count = 0;
-                curr_seq_length = 0;
+        let opposing_in_block = against.count_ones() as u64;
+        if opposing + opposing_in_block >= self.config.jump_trigger {
+            return None;
+        }
 
-                if curr_pos >= size_usize || curr_pos >= buff.len() {
-                    break;
+        // The run carried in continues up to the first opposing slope
+        let first_break = if against == 0 { BLOCK_LANES as u32 } else { against.trailing_zeros() };
+        if seq_length + (along & ((1 << first_break) - 1)).count_ones() as u64 >= threshold {
+            return None;
+        }
+
+        let run = if (along | against).count_ones() == BLOCK_LANES as u32 {
+            // Without equal bytes a sequence is a run of set bits, and each
+            // `m & (m >> 1)` shortens every run by one
+            let mut longest = along;
+            for _ in 1..threshold.min(BLOCK_LANES as u64 + 1) {
+                longest &= longest >> 1;
+            }
+            if threshold <= BLOCK_LANES as u64 && longest != 0 {
+                return None;
+            }
+            match against {
+                0 => seq_length + BLOCK_LANES as u64,
+                _ => (along >> (32 - against.leading_zeros())).count_ones() as u64,
+            }
+        } else {
+            // Equal bytes neither extend nor break a sequence, so check each
+            // run where it ends
+            let mut run = seq_length;
+            let mut rest = along;
+            let mut breaks = against;
+            loop {
+                let run_end = if breaks == 0 { BLOCK_LANES as u32 } else { breaks.trailing_zeros() };
+                let run_mask = rest & ((1 << run_end) - 1);
+                run += run_mask.count_ones() as u64;
+                if run >= threshold {
+                    return None;
                 }
-            } else {
-                curr_pos += 1;
+                if breaks == 0 {
+                    break run;
+                }
+                rest &= !run_mask;
+                breaks &= breaks - 1;
+                run = 0;
             }
-        }
+        };
 
-        size
+        let absorbed = (BLOCK_LANES as u32 - (along | against).count_ones()) as u64;
+        Some((opposing_in_block, run, absorbed))
     }
 
-    /// Find the cutpoint for decreasing sequences
-    fn find_cutpoint_decreasing(&self, buff: &[u8], size: u64) -> u64 {
-        let mut curr_pos = self.config.min_block_size as usize;
+    /// Find the cutpoint for increasing sequences
+    ///
+    /// `STEPPED` selects `window_step`; as a constant it keeps the default
+    /// one-position advance free of a runtime step.
+    fn find_cutpoint_increasing<const STEPPED: bool>(&self, buff: &[T], size: u64, min_size: u64, mut sentinel: Option<i64>, obs: &mut impl ScanObserver) -> Option<u64> {
+        // Positions are tracked as u64 so results never depend on pointer width
+        // Warm-up positions count towards runs but never cut
+        let mut curr_pos: u64 = min_size - self.config.warmup_bytes.min(min_size.saturating_sub(1));
         let mut opposing_slope_count: u64 = 0;
         let mut curr_seq_length: u64 = 0;
-        let size_usize = size as usize;
+        let mut jump_count: u64 = 0;
+        let mut absorbed_count: u64 = 0;
+        let mut scanned_count: u64 = 0;
+        let scan_end = size.min(buff.len() as u64);
+        let avg_pos = self.config.avg_block_size;
+        let (strict_threshold, lenient_threshold) = self.normalized_thresholds();
+        // Equal bytes and jumps leave the alternation count untouched
+        let alternation_limit = self.config.alternation_threshold;
+        // Equal bytes only reach the sign test when not absorbed, and then
+        // oppose: widening the test by one avoids a branch on the option
+        let absorb_equal = self.config.absorb_equal_bytes;
+        let equal_opposes = !absorb_equal as i64;
+        let step = if STEPPED { self.config.slope_window } else { 1 };
+        let mut alternation: u64 = 0;
+        let mut last_opposing = false;
+        #[cfg(feature = "simd")]
+        let mut scalar_until: u64 = 0;
+
+        let cut = 'scan: {
+            while curr_pos < scan_end && curr_pos > 0 {
+                // Skip whole blocks that cannot cut; a block that might is scanned
+                // one position at a time before trying again
+                #[cfg(feature = "simd")]
+                if sentinel.is_none() && curr_pos >= scalar_until {
+                    match self.skip_block(buff, curr_pos, scan_end, true, curr_seq_length, opposing_slope_count) {
+                        Some((opposing, seq_length, absorbed)) => {
+                            opposing_slope_count += opposing;
+                            curr_seq_length = seq_length;
+                            absorbed_count += absorbed;
+                            scanned_count += BLOCK_LANES as u64;
+                            curr_pos += BLOCK_LANES as u64;
+                            continue;
+                        }
+                        None => scalar_until = curr_pos + BLOCK_LANES as u64,
+                    }
+                }
 
-        while curr_pos < size_usize && curr_pos < buff.len() && curr_pos > 0 {
-            let cmp_result = buff[curr_pos] as i16 - buff[curr_pos - 1] as i16;
+                // The first scanned position may compare against a sentinel predecessor
+                let cmp_result = match sentinel.take() {
+                    Some(prev) => self.element_diff(buff[curr_pos as usize].to_i64(), prev),
+                    None => self.slope(buff, curr_pos as usize),
+                };
+
+                scanned_count += 1;
+
+                // Low Entropy Absorption - skip equal bytes
+                if cmp_result == 0 && absorb_equal {
+                    absorbed_count += 1;
+                    if curr_pos >= min_size && self.absorption_exceeded(absorbed_count, scanned_count) {
+                        break 'scan Some(curr_pos);
+                    }
+                    curr_pos += step;
+                    continue;
+                }
 
-            // Low Entropy Absorption - skip equal bytes
-            if cmp_result == 0 {
-                curr_pos += 1;
-                continue;
-            }
+                let cmp_sign = cmp_result < equal_opposes;
 
-            let cmp_sign = cmp_result > 0;
+                if cmp_sign {
+                    opposing_slope_count += 1;
+                    curr_seq_length = 0;
+                } else {
+                    curr_seq_length += 1;
+                }
 
-            if cmp_sign {
-                opposing_slope_count += 1;
-                curr_seq_length = 0;
-            } else {
-                curr_seq_length += 1;
-            }
+                let threshold = if curr_pos < avg_pos { strict_threshold } else { lenient_threshold };
+                if curr_seq_length >= threshold && curr_pos >= min_size {
+                    break 'scan Some(curr_pos);
+                }
 
-            if curr_seq_length >= self.config.seq_threshold {
-                return curr_pos as u64;
+                // Only tracked when enabled: the update branches on the slope sign
+                if let Some(limit) = alternation_limit {
+                    alternation = if cmp_sign != last_opposing { alternation + 1 } else { 1 };
+                    last_opposing = cmp_sign;
+                    if !cmp_sign && alternation >= limit && curr_pos >= min_size {
+                        break 'scan Some(curr_pos);
+                    }
+                }
+
+                if opposing_slope_count >= self.config.jump_trigger {
+                    curr_pos = curr_pos.saturating_add(self.config.jump_size);
+                    opposing_slope_count = 0;
+                    curr_seq_length = 0;
+                    jump_count += 1;
+
+                    if curr_pos >= scan_end {
+                        break;
+                    }
+
+                    // Bound the damage of repeated jumps by forcing a cut
+                    if curr_pos >= min_size && self.config.max_consecutive_jumps.is_some_and(|cap| jump_count >= cap) {
+                        break 'scan Some(curr_pos);
+                    }
+                } else {
+                    curr_pos += step;
+                }
             }
 
-            if opposing_slope_count >= self.config.jump_trigger {
-                curr_pos += self.config.jump_size as usize;
-                opposing_slope_count = 0;
-                curr_seq_length = 0;
+            None
+        };
 
-                if curr_pos >= size_usize || curr_pos >= buff.len() {
-                    break;
+        obs.scan_finished(scanned_count, absorbed_count, jump_count);
+        cut
+    }
+
+    /// Find the cutpoint for decreasing sequences, see [`find_cutpoint_increasing`](Self::find_cutpoint_increasing)
+    fn find_cutpoint_decreasing<const STEPPED: bool>(&self, buff: &[T], size: u64, min_size: u64, mut sentinel: Option<i64>, obs: &mut impl ScanObserver) -> Option<u64> {
+        // Positions are tracked as u64 so results never depend on pointer width
+        // Warm-up positions count towards runs but never cut
+        let mut curr_pos: u64 = min_size - self.config.warmup_bytes.min(min_size.saturating_sub(1));
+        let mut opposing_slope_count: u64 = 0;
+        let mut curr_seq_length: u64 = 0;
+        let mut jump_count: u64 = 0;
+        let mut absorbed_count: u64 = 0;
+        let mut scanned_count: u64 = 0;
+        let scan_end = size.min(buff.len() as u64);
+        let avg_pos = self.config.avg_block_size;
+        let (strict_threshold, lenient_threshold) = self.normalized_thresholds();
+        // Equal bytes and jumps leave the alternation count untouched
+        let alternation_limit = self.config.alternation_threshold;
+        // Equal bytes only reach the sign test when not absorbed, and then
+        // oppose: widening the test by one avoids a branch on the option
+        let absorb_equal = self.config.absorb_equal_bytes;
+        let equal_opposes = !absorb_equal as i64;
+        let step = if STEPPED { self.config.slope_window } else { 1 };
+        let mut alternation: u64 = 0;
+        let mut last_opposing = false;
+        #[cfg(feature = "simd")]
+        let mut scalar_until: u64 = 0;
+
+        let cut = 'scan: {
+            while curr_pos < scan_end && curr_pos > 0 {
+                // Skip whole blocks that cannot cut; a block that might is scanned
+                // one position at a time before trying again
+                #[cfg(feature = "simd")]
+                if sentinel.is_none() && curr_pos >= scalar_until {
+                    match self.skip_block(buff, curr_pos, scan_end, false, curr_seq_length, opposing_slope_count) {
+                        Some((opposing, seq_length, absorbed)) => {
+                            opposing_slope_count += opposing;
+                            curr_seq_length = seq_length;
+                            absorbed_count += absorbed;
+                            scanned_count += BLOCK_LANES as u64;
+                            curr_pos += BLOCK_LANES as u64;
+                            continue;
+                        }
+                        None => scalar_until = curr_pos + BLOCK_LANES as u64,
+                    }
+                }
+
+                // The first scanned position may compare against a sentinel predecessor
+                let cmp_result = match sentinel.take() {
+                    Some(prev) => self.element_diff(buff[curr_pos as usize].to_i64(), prev),
+                    None => self.slope(buff, curr_pos as usize),
+                };
+
+                scanned_count += 1;
+
+                // Low Entropy Absorption - skip equal bytes
+                if cmp_result == 0 && absorb_equal {
+                    absorbed_count += 1;
+                    if curr_pos >= min_size && self.absorption_exceeded(absorbed_count, scanned_count) {
+                        break 'scan Some(curr_pos);
+                    }
+                    curr_pos += step;
+                    continue;
+                }
+
+                let cmp_sign = cmp_result > -equal_opposes;
+
+                if cmp_sign {
+                    opposing_slope_count += 1;
+                    curr_seq_length = 0;
+                } else {
+                    curr_seq_length += 1;
+                }
+
+                let threshold = if curr_pos < avg_pos { strict_threshold } else { lenient_threshold };
+                if curr_seq_length >= threshold && curr_pos >= min_size {
+                    break 'scan Some(curr_pos);
+                }
+
+                // Only tracked when enabled: the update branches on the slope sign
+                if let Some(limit) = alternation_limit {
+                    alternation = if cmp_sign != last_opposing { alternation + 1 } else { 1 };
+                    last_opposing = cmp_sign;
+                    if !cmp_sign && alternation >= limit && curr_pos >= min_size {
+                        break 'scan Some(curr_pos);
+                    }
+                }
+
+                if opposing_slope_count >= self.config.jump_trigger {
+                    curr_pos = curr_pos.saturating_add(self.config.jump_size);
+                    opposing_slope_count = 0;
+                    curr_seq_length = 0;
+                    jump_count += 1;
+
+                    if curr_pos >= scan_end {
+                        break;
+                    }
+
+                    // Bound the damage of repeated jumps by forcing a cut
+                    if curr_pos >= min_size && self.config.max_consecutive_jumps.is_some_and(|cap| jump_count >= cap) {
+                        break 'scan Some(curr_pos);
+                    }
+                } else {
+                    curr_pos += step;
                 }
-            } else {
-                curr_pos += 1;
             }
-        }
 
-        size
+            None
+        };
+
+        obs.scan_finished(scanned_count, absorbed_count, jump_count);
+        cut
+    }
+
+    /// Find the cutpoint with a Gear rolling hash
+    ///
+    /// Elements are hashed by their low byte.
+    fn find_cutpoint_gear(&self, buff: &[T], size: u64, min_size: u64, mask: u64, obs: &mut impl ScanObserver) -> Option<u64> {
+        let scan_end = size.min(buff.len() as u64);
+        let scan_start = min_size.saturating_sub(self.config.warmup_bytes);
+        let mut hash: u64 = 0;
+
+        let cut = (scan_start..scan_end)
+            .find(|&pos| {
+                let byte = buff[pos as usize].to_i64() as u8;
+                hash = (hash << 1).wrapping_add(GEAR_TABLE[byte as usize]);
+                hash & mask == 0 && pos >= min_size
+            })
+            .map(|pos| pos + 1);
+
+        obs.scan_finished(cut.unwrap_or(scan_end).saturating_sub(scan_start), 0, 0);
+        cut
     }
 
     /// Find the optimal cutpoint in the given buffer
-    pub fn find_cutpoint(&self, buff: &[u8], size: u64) -> u64 {
-        if size < self.config.min_block_size {
-            return size;
+    ///
+    /// The result never exceeds `buff.len()`, even when `size` does.
+    pub fn find_cutpoint(&self, buff: &[T], size: u64) -> u64 {
+        self.find_cutpoint_with_min(buff, size, self.config.min_block_size, None, &mut ())
+    }
+
+    /// Find the cutpoint for the chunk starting at `position` of the stream
+    ///
+    /// At the start of the stream this applies the reduced minimum size of
+    /// `fast_first_chunk` and the predecessor given by `leading_sentinel`.
+    pub(crate) fn find_cutpoint_at(&self, buff: &[T], size: u64, position: usize) -> u64 {
+        self.find_cutpoint_observed(buff, size, position, &mut ())
+    }
+
+    /// [`find_cutpoint_at`](Self::find_cutpoint_at), reporting the scan work to `obs`
+    fn find_cutpoint_observed(&self, buff: &[T], size: u64, position: usize, obs: &mut impl ScanObserver) -> u64 {
+        let min_size = self.chunk_min_size(position);
+        if position != 0 {
+            return self.find_cutpoint_with_min(buff, size, min_size, None, obs);
         }
 
-        let actual_size = size.min(self.config.max_block_size);
+        let sentinel = self.config.leading_sentinel.map(i64::from);
+        self.find_cutpoint_with_min(buff, size, min_size, sentinel, obs)
+    }
 
-        match self.config.op_mode {
-            SeqOpMode::Increasing => self.find_cutpoint_increasing(buff, actual_size),
-            SeqOpMode::Decreasing => self.find_cutpoint_decreasing(buff, actual_size),
+    /// Minimum size of the chunk starting at `position` of the stream
+    fn chunk_min_size(&self, position: usize) -> u64 {
+        if position == 0 && self.config.fast_first_chunk {
+            self.config.min_block_size.min(FAST_FIRST_CHUNK_MIN_SIZE)
+        } else {
+            self.config.min_block_size
         }
     }
 
-    /// Create an iterator over all chunks in the given data
-    pub fn chunk_all<'a>(&'a self, data: &'a [u8]) -> ChunkIterator<'a> {
-        ChunkIterator::new(data, self)
+    /// Length of the next chunk, which starts `remaining` at `position` of the stream
+    fn next_chunk_len(&self, remaining: &[T], position: usize, prev_len: Option<usize>, obs: &mut impl ScanObserver) -> usize {
+        let cutpoint = self.find_cutpoint_observed(remaining, remaining.len() as u64, position, obs);
+        self.smooth_len(cutpoint as usize, prev_len, remaining.len()).min(remaining.len())
     }
 
-    /// Chunk the data and collect all chunks into a Vec
-    pub fn chunk_all_vec<'a>(&'a self, data: &'a [u8]) -> Vec<Chunk<'a>> {
-        self.chunk_all(data).collect()
+    /// Length of the run of identical elements at the start of `remaining`,
+    /// or 0 when it does not fill a whole scan window
+    ///
+    /// Every position of such a window is absorbed, so no cut can be found
+    /// there unless absorption itself forces one or the leading sentinel
+    /// breaks the run.
+    fn uniform_run(&self, remaining: &[T], position: usize) -> usize {
+        let window = remaining.len().min(self.config.max_block_size as usize);
+        let applicable = self.config.cut_strategy == CutStrategy::Slope
+            && self.config.max_absorption_ratio.is_none()
+            && self.config.absorb_equal_bytes
+            && !(position == 0 && self.config.leading_sentinel.is_some());
+        let Some(&first) = remaining.first().filter(|_| applicable) else {
+            return 0;
+        };
+
+        let run = remaining.iter().position(|&element| element != first).unwrap_or(remaining.len());
+        if run >= window { run } else { 0 }
     }
 
-    /// Get the first chunk from the data
-    pub fn chunk_first<'a>(&'a self, data: &'a [u8]) -> Option<Chunk<'a>> {
-        self.chunk_all(data).next()
+    /// Length of a chunk whose whole scan window is one [`uniform_run`](Self::uniform_run)
+    fn uniform_chunk_len(&self, remaining: usize, position: usize, prev_len: Option<usize>) -> usize {
+        let size = remaining as u64;
+        let min_size = self.chunk_min_size(position);
+        let cut = if size < min_size { size } else { self.fallback_cut(size, min_size) };
+        self.smooth_len(cut as usize, prev_len, remaining).min(remaining)
     }
 
-    /// Calculate chunking statistics for the given data
-    pub fn stats(&self, data: &[u8]) -> ChunkingStats {
-        let chunks: Vec<_> = self.chunk_all(data).collect();
-        ChunkingStats::from_chunks(&chunks, data.len())
+    /// End of the scan for a chunk of at most `size` elements
+    fn scan_limit(&self, size: u64, min_size: u64) -> u64 {
+        let actual_size = size.min(self.config.max_block_size);
+        self.config
+            .max_scan_distance
+            .map_or(actual_size, |distance| actual_size.min(min_size.saturating_add(distance)))
     }
-}
 
-impl Default for SeqChunking {
-    fn default() -> Self {
-        Self::new()
+    /// Where to cut when a scan of a chunk of at most `size` elements found no trigger
+    fn fallback_cut(&self, size: u64, min_size: u64) -> u64 {
+        let actual_size = size.min(self.config.max_block_size);
+        let limit = self.scan_limit(size, min_size);
+        // A scan stopped by `max_scan_distance` cuts where it stopped
+        if limit < actual_size {
+            return limit;
+        }
+        match self.config.fallback {
+            FallbackPolicy::MaxBlock => actual_size,
+            FallbackPolicy::AvgBlock => actual_size.min(self.config.avg_block_size),
+            FallbackPolicy::FullRemaining => size,
+        }
     }
-}
 
-/// Statistics about chunking results
-#[derive(Debug, Clone)]
-pub struct ChunkingStats {
-    /// Total number of chunks
-    pub chunk_count: usize,
-    /// Total size of data processed
-    pub total_size: usize,
-    /// Average chunk size
-    pub avg_chunk_size: f64,
-    /// Minimum chunk size
-    pub min_chunk_size: usize,
-    /// Maximum chunk size
-    pub max_chunk_size: usize,
-    /// Standard deviation of chunk sizes
-    pub chunk_size_stddev: f64,
-}
+    fn find_cutpoint_with_min(&self, buff: &[T], size: u64, min_size: u64, sentinel: Option<i64>, obs: &mut impl ScanObserver) -> u64 {
+        // Never cut past the end of the buffer, whatever size was asked for
+        let size = size.min(buff.len() as u64);
+        if size < min_size {
+            return size;
+        }
 
-impl ChunkingStats {
-    /// Create statistics from a collection of chunks
-    pub fn from_chunks(chunks: &[Chunk<'_>], total_size: usize) -> Self {
-        if chunks.is_empty() {
-            return Self {
-                chunk_count: 0,
-                total_size,
-                avg_chunk_size: 0.0,
-                min_chunk_size: 0,
-                max_chunk_size: 0,
-                chunk_size_stddev: 0.0,
-            };
+        let actual_size = self.scan_limit(size, min_size);
+
+        let stepped = self.config.window_step && self.config.slope_window > 1;
+        let mut scan = |min_size, sentinel| match (self.config.cut_strategy, self.config.op_mode, stepped) {
+            (CutStrategy::Gear { mask }, _, _) => self.find_cutpoint_gear(buff, actual_size, min_size, mask, obs),
+            (CutStrategy::Slope, SeqOpMode::Increasing, false) => {
+                self.find_cutpoint_increasing::<false>(buff, actual_size, min_size, sentinel, obs)
+            }
+            (CutStrategy::Slope, SeqOpMode::Increasing, true) => {
+                self.find_cutpoint_increasing::<true>(buff, actual_size, min_size, sentinel, obs)
+            }
+            (CutStrategy::Slope, SeqOpMode::Decreasing, false) => {
+                self.find_cutpoint_decreasing::<false>(buff, actual_size, min_size, sentinel, obs)
+            }
+            (CutStrategy::Slope, SeqOpMode::Decreasing, true) => {
+                self.find_cutpoint_decreasing::<true>(buff, actual_size, min_size, sentinel, obs)
+            }
+        };
+        let mut cut = scan(min_size, sentinel);
+
+        // Keep scanning past cuts that would leave a too uniform chunk, merging
+        // it into a later cut or the fallback
+        if let Some(min_entropy) = self.config.min_chunk_entropy {
+            // Wide elements count each of their bytes, so the gate stays in bits per byte
+            let width = core::mem::size_of::<T>();
+            let mut counts = [0u64; 256];
+            let mut counted = 0;
+            while let Some(candidate) = cut {
+                for element in &buff[counted as usize..candidate as usize] {
+                    for &byte in &element.to_i64().to_le_bytes()[..width] {
+                        counts[byte as usize] += 1;
+                    }
+                }
+                counted = candidate;
+                if entropy_from_counts(&counts, counted * width as u64) >= min_entropy {
+                    break;
+                }
+                cut = scan(candidate + 1, None);
+            }
         }
 
-        let chunk_sizes: Vec<usize> = chunks.iter().map(|c| c.len).collect();
-        let chunk_count = chunks.len();
-        let sum: usize = chunk_sizes.iter().sum();
-        let avg = sum as f64 / chunk_count as f64;
+        if cut.is_none() {
+            obs.fallback_cut();
+        }
 
-        let min_size = chunk_sizes.iter().min().copied().unwrap_or(0);
-        let max_size = chunk_sizes.iter().max().copied().unwrap_or(0);
+        cut.unwrap_or_else(|| self.fallback_cut(size, min_size))
+    }
 
-        // Calculate standard deviation
-        let variance: f64 = chunk_sizes
-            .iter()
-            .map(|&size| {
-                let diff = size as f64 - avg;
-                diff * diff
-            })
-            .sum::<f64>()
-            / chunk_count as f64;
+    /// Create an iterator over all chunks in the given data
+    ///
+    /// Empty data yields no chunks; every chunk yielded is non-empty.
+    pub fn chunk_all<'a>(&'a self, data: &'a [T]) -> ChunkIterator<'a, T> {
+        ChunkIterator::new(data, self)
+    }
 
-        let stddev = variance.sqrt();
+    /// Continue chunking `data` from a boundary saved by an earlier run
+    ///
+    /// `last_boundary` must be the start of a chunk of [`chunk_all`](Self::chunk_all),
+    /// e.g. a persisted checkpoint. The chunks from there on are the same as
+    /// those of `chunk_all`, with absolute offsets. The iterator is empty when
+    /// `last_boundary` is past the end of the data. With `max_size_ratio` the
+    /// size of the chunk before the checkpoint is unknown, so the first
+    /// resumed chunk is not smoothed against it.
+    pub fn resume_from<'a>(&'a self, data: &'a [T], last_boundary: usize) -> ChunkIterator<'a, T> {
+        ChunkIterator::starting_at(data, self, last_boundary)
+    }
 
-        Self {
-            chunk_count,
-            total_size,
-            avg_chunk_size: avg,
-            min_chunk_size: min_size,
-            max_chunk_size: max_size,
-            chunk_size_stddev: stddev,
+    /// Chunk the data like [`chunk_all`](Self::chunk_all), also measuring the scan work
+    ///
+    /// The counters are only collected here; other chunking calls run the
+    /// scan without them.
+    pub fn chunk_all_instrumented<'a>(&self, data: &'a [T]) -> (Vec<Chunk<'a, T>>, ScanMetrics) {
+        let mut metrics = ScanMetrics::default();
+        let mut chunks = Vec::new();
+        let mut position = 0;
+        let mut prev_len = None;
+
+        while position < data.len() {
+            let remaining = &data[position..];
+            let len = self.next_chunk_len(remaining, position, prev_len, &mut metrics);
+            if len == 0 {
+                break;
+            }
+            chunks.push(Chunk::new(&remaining[..len], position, len));
+            position += len;
+            prev_len = Some(len);
         }
+
+        (chunks, metrics)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::config::SeqOpMode;
+    /// Iterate over the chunks of [`chunk_all`](Self::chunk_all) along with why each one was cut
+    ///
+    /// Useful when tuning parameters, e.g. to see what share of the chunks
+    /// are forced [`CutReason::MaxBlockSize`] cuts rather than content-defined.
+    /// Cuts moved by `max_size_ratio` are reported as [`CutReason::SizeRatio`],
+    /// whatever the scan found.
+    pub fn chunk_all_with_reasons<'a>(&'a self, data: &'a [T]) -> impl Iterator<Item = (Chunk<'a, T>, CutReason)> + 'a {
+        let mut position = 0;
+        let mut prev_len = None;
+        core::iter::from_fn(move || {
+            let remaining = &data[position..];
+            let mut observer = TriggerObserver::default();
+            // As in `next_chunk_len`, keeping the cut from before smoothing
+            let cutpoint = self.find_cutpoint_observed(remaining, remaining.len() as u64, position, &mut observer);
+            let cutpoint = (cutpoint as usize).min(remaining.len());
+            let len = self.smooth_len(cutpoint, prev_len, remaining.len()).min(remaining.len());
+            if len == 0 {
+                return None;
+            }
 
-    #[test]
-    fn test_seq_chunking_new() {
+            let reason = if len != cutpoint {
+                CutReason::SizeRatio
+            } else if observer.scanned && !observer.fell_back {
+                CutReason::SeqThreshold
+            } else if len == remaining.len() && len as u64 != self.config.max_block_size {
+                CutReason::EndOfData
+            } else {
+                CutReason::MaxBlockSize
+            };
+            let chunk = Chunk::new(&remaining[..len], position, len);
+            position += len;
+            prev_len = Some(len);
+            Some((chunk, reason))
+        })
+    }
+
+    /// Create an iterator over all chunks, enforcing `small_input_policy`
+    ///
+    /// With [`SmallInputPolicy::Error`], non-empty data shorter than
+    /// `min_block_size` is rejected instead of becoming a single chunk.
+    pub fn try_chunk_all<'a>(&'a self, data: &'a [T]) -> Result<ChunkIterator<'a, T>> {
+        let too_small = !data.is_empty() && (data.len() as u64) < self.config.min_block_size;
+        if too_small && self.config.small_input_policy == SmallInputPolicy::Error {
+            return Err(ChunkingError::invalid_input(format!(
+                "input of {} elements is shorter than min_block_size ({})",
+                data.len(),
+                self.config.min_block_size
+            )));
+        }
+        Ok(self.chunk_all(data))
+    }
+
+    /// Create an iterator over the chunks of data that starts at `base_offset` of a larger stream
+    ///
+    /// Chunk `start` fields are offset by `base_offset`. Unless `base_offset` is
+    /// 0, the first chunk is not treated as the start of the stream, so
+    /// `fast_first_chunk` and `leading_sentinel` do not apply to it.
+    pub fn chunk_all_from<'a>(&'a self, data: &'a [T], base_offset: usize) -> ChunkIterator<'a, T> {
+        let mut iter = ChunkIterator::new(data, self);
+        iter.base_offset = base_offset;
+        iter
+    }
+
+    /// Lazily apply `f` to each chunk of the data
+    ///
+    /// Equivalent to `chunk_all(data).map(f)`.
+    ///
+    /// ```
+    /// use seq_chunking::SeqChunking;
+    ///
+    /// let chunker = SeqChunking::new();
+    /// let data = vec![0u8; 40_000];
+    /// let lengths: Vec<usize> = chunker.map_chunks(&data, |chunk| chunk.len).collect();
+    /// assert_eq!(lengths.iter().sum::<usize>(), data.len());
+    /// ```
+    pub fn map_chunks<'a, U, F>(&'a self, data: &'a [T], f: F) -> impl Iterator<Item = U> + 'a
+    where
+        F: FnMut(Chunk<'a, T>) -> U + 'a,
+    {
+        self.chunk_all(data).map(f)
+    }
+
+    /// Chunk the data and collect all chunks into a Vec
+    pub fn chunk_all_vec<'a>(&'a self, data: &'a [T]) -> Vec<Chunk<'a, T>> {
+        self.chunk_all(data).collect()
+    }
+
+    /// Estimate how many chunks the input will produce, based on `avg_block_size`
+    pub fn estimate_chunk_count(&self, data_len: usize) -> usize {
+        let avg = self.config.avg_block_size.max(1);
+        (data_len as u64).div_ceil(avg) as usize
+    }
+
+    /// Estimate the memory needed to collect all chunks with [`SeqChunker::chunk_all_vec`]
+    ///
+    /// Chunks borrow the input, so only the `Chunk` values themselves are
+    /// counted. This is an O(1) estimate based on `avg_block_size`.
+    pub fn estimated_memory(&self, data_len: usize) -> usize {
+        self.estimate_chunk_count(data_len) * core::mem::size_of::<Chunk<'_, T>>()
+    }
+
+    /// Compute the exclusive end offset of every chunk
+    ///
+    /// The offsets match the chunks of [`SeqChunker::chunk_all`] exactly; for
+    /// non-empty input the last offset equals `data.len()`.
+    pub fn chunk_offsets(&self, data: &[T]) -> Vec<usize> {
+        self.chunk_all(data).map(|chunk| chunk.end()).collect()
+    }
+
+    /// Chunk the data from its end towards its start
+    ///
+    /// Boundaries are anchored to the end of the data: the slope at element
+    /// `i` compares it with element `i + 1`, and each chunk's cut is searched
+    /// backwards from the end of the chunk after it. Data prepended at the
+    /// front therefore leaves the tail chunks unchanged, which suits files
+    /// whose tail is stable. Chunks are returned in forward order with their
+    /// forward `start`. A reversed copy of the data is scanned, so this
+    /// allocates one copy of the input.
+    pub fn chunk_all_reverse<'a>(&self, data: &'a [T]) -> Vec<Chunk<'a, T>> {
+        let reversed: Vec<T> = data.iter().rev().copied().collect();
+
+        // Each chunk of the reversed data ends where its forward chunk starts
+        let mut chunks = Vec::new();
+        let mut end = data.len();
+        for reversed_end in self.chunk_offsets(&reversed) {
+            let start = data.len() - reversed_end;
+            chunks.push(Chunk::new(&data[start..end], start, end - start));
+            end = start;
+        }
+        chunks.reverse();
+        chunks
+    }
+
+    /// The chunks of [`chunk_all`](Self::chunk_all), from last to first
+    ///
+    /// Boundaries only follow from a forward scan, so all of them are found
+    /// upfront; the chunks themselves are built as the iterator advances.
+    pub fn chunk_all_rev_iter<'a>(&'a self, data: &'a [T]) -> impl Iterator<Item = Chunk<'a, T>> + 'a {
+        let ends = self.chunk_offsets(data);
+        (0..ends.len()).rev().map(move |i| {
+            let start = if i == 0 { 0 } else { ends[i - 1] };
+            Chunk::new(&data[start..ends[i]], start, ends[i] - start)
+        })
+    }
+
+    /// Chunk end offsets with at least one boundary in every `anchor_stride` window
+    ///
+    /// Slope cuts are kept as they are. Wherever two consecutive boundaries
+    /// (starting from offset 0) are more than `anchor_stride` apart, the fewest
+    /// forced boundaries that bound the gap are spread evenly across it. Since
+    /// slope cuts are unaffected, parties chunking related data still agree on
+    /// them. An `anchor_stride` of 0 is treated as 1.
+    ///
+    /// No forced boundary is placed closer than `suppress_window` to its
+    /// neighbours. Even spacing already keeps them more than `anchor_stride / 2`
+    /// apart; a larger `suppress_window` takes precedence and may leave gaps
+    /// above `anchor_stride`. Slope cuts are never closer than `min_block_size`
+    /// to each other, so the window only matters around forced cuts.
+    pub fn anchored_boundaries(&self, data: &[T], anchor_stride: usize) -> Vec<usize> {
+        let stride = anchor_stride.max(1);
+        let suppress = (self.config.suppress_window as usize).max(1);
+        let mut boundaries = Vec::new();
+        let mut previous = 0;
+
+        for cut in self.chunk_offsets(data) {
+            let gap = cut - previous;
+            let pieces = gap.div_ceil(stride).min((gap / suppress).max(1));
+            for k in 1..pieces {
+                boundaries.push(previous + k * gap / pieces);
+            }
+            boundaries.push(cut);
+            previous = cut;
+        }
+
+        boundaries
+    }
+
+    /// Get the first chunk from the data, `None` only when the data is empty
+    pub fn chunk_first<'a>(&'a self, data: &'a [T]) -> Option<Chunk<'a, T>> {
+        self.chunk_all(data).next()
+    }
+}
+
+impl SeqChunking {
+    /// Stable fingerprint of the cut logic and every boundary-relevant setting
+    ///
+    /// Two chunkers with the same fingerprint produce the same boundaries for
+    /// any input under `chunk_all`. Settings are hashed by effect, so
+    /// configurations that cannot cut differently share a fingerprint, e.g. an
+    /// `avg_block_size` that neither normalization, a relaxed threshold nor the
+    /// fallback policy reads. [`ALGORITHM_VERSION`] is included so caches and
+    /// manifests can detect changes to the algorithm itself.
+    pub fn algorithm_fingerprint(&self) -> u64 {
+        let config = &self.config;
+        let mut hash = Fnv1a::new();
+        hash.write(ALGORITHM_VERSION as u64);
+        hash.write(config.min_block_size);
+        hash.write(config.max_block_size);
+
+        let first_min = if config.fast_first_chunk {
+            config.min_block_size.min(FAST_FIRST_CHUNK_MIN_SIZE)
+        } else {
+            config.min_block_size
+        };
+        hash.write(first_min);
+
+        let (strict, lenient) = self.normalized_thresholds();
+        let avg_fallback = config.fallback == FallbackPolicy::AvgBlock;
+        let reads_avg = avg_fallback || (strict != lenient && config.cut_strategy == CutStrategy::Slope);
+        hash.write(config.fallback as u64);
+        hash.write(if reads_avg { config.avg_block_size } else { 0 });
+
+        match config.cut_strategy {
+            CutStrategy::Gear { mask } => {
+                hash.write(1);
+                hash.write(mask);
+            }
+            CutStrategy::Slope => {
+                hash.write(0);
+                hash.write(config.op_mode as u64);
+                hash.write(strict);
+                hash.write(lenient);
+                hash.write(config.jump_trigger);
+                hash.write(config.jump_size);
+                hash.write(config.transform as u64);
+                hash.write(config.slope_window);
+                hash.write(config.max_consecutive_jumps.map_or(0, |cap| cap + 1));
+                hash.write(config.max_absorption_ratio.map_or(0, |ratio| ratio.to_bits() as u64 + 1));
+                hash.write(config.leading_sentinel.map_or(0, |byte| byte as u64 + 1));
+            }
+        }
+
+        // Options added later are only hashed when enabled, so fingerprints of
+        // configurations that don't use them stay the same
+        if config.wrapping_diff && config.cut_strategy == CutStrategy::Slope {
+            hash.write(0x77);
+        }
+        if let Some(entropy) = config.min_chunk_entropy {
+            hash.write(0x78);
+            hash.write(entropy.to_bits());
+        }
+        if let Some(ratio) = config.max_size_ratio {
+            hash.write(0x79);
+            hash.write(ratio.to_bits());
+        }
+        if let (Some(threshold), CutStrategy::Slope) = (config.alternation_threshold, config.cut_strategy) {
+            hash.write(0x7a);
+            hash.write(threshold);
+        }
+        if config.window_step && config.slope_window > 1 && config.cut_strategy == CutStrategy::Slope {
+            hash.write(0x7b);
+        }
+        if config.warmup_bytes > 0 {
+            hash.write(0x7c);
+            hash.write(config.warmup_bytes);
+        }
+        if !config.absorb_equal_bytes && config.cut_strategy == CutStrategy::Slope {
+            hash.write(0x7d);
+        }
+        if let Some(distance) = config.max_scan_distance {
+            hash.write(0x7e);
+            hash.write(distance);
+        }
+
+        hash.finish()
+    }
+
+    /// Suggest a `seq_threshold` from the run lengths found in `sample`
+    ///
+    /// Runs are maximal sequences of steps in the `op_mode` direction, with
+    /// equal bytes absorbed as in the scan. The 95th percentile of their
+    /// lengths is returned, so cuts fire on runs longer than the common noise
+    /// of the data. Returns [`DEFAULT_SEQ_THRESHOLD`](crate::DEFAULT_SEQ_THRESHOLD)
+    /// when the sample holds no run.
+    pub fn suggest_seq_threshold(sample: &[u8], op_mode: SeqOpMode) -> u64 {
+        // histogram[len] counts runs of `len` steps
+        let mut histogram: Vec<u64> = Vec::new();
+        let mut record = |run: usize| {
+            if run > 0 {
+                if histogram.len() <= run {
+                    histogram.resize(run + 1, 0);
+                }
+                histogram[run] += 1;
+            }
+        };
+
+        let mut run = 0;
+        for pair in sample.windows(2) {
+            let step = match op_mode {
+                SeqOpMode::Increasing => pair[1].cmp(&pair[0]),
+                SeqOpMode::Decreasing => pair[0].cmp(&pair[1]),
+            };
+            match step {
+                core::cmp::Ordering::Greater => run += 1,
+                core::cmp::Ordering::Less => {
+                    record(run);
+                    run = 0;
+                }
+                core::cmp::Ordering::Equal => {}
+            }
+        }
+        record(run);
+
+        let total: u64 = histogram.iter().sum();
+        if total == 0 {
+            return crate::DEFAULT_SEQ_THRESHOLD;
+        }
+        let rank = (total * SUGGESTED_THRESHOLD_PERCENTILE).div_ceil(100);
+        let mut seen = 0;
+        histogram
+            .iter()
+            .position(|&count| {
+                seen += count;
+                seen >= rank
+            })
+            .map_or(crate::DEFAULT_SEQ_THRESHOLD, |len| len as u64)
+    }
+
+    /// Tune `seq_threshold` and `jump_trigger` so the average chunk size on `sample` approaches `target_avg`
+    ///
+    /// Binary-searches `seq_threshold` first. If no threshold lands within 10%
+    /// of the target, `jump_trigger` is then searched for the thresholds that
+    /// stay below it, largest first, since more frequent jumps lengthen
+    /// chunks. Gives up after a fixed number of trials and returns the closest
+    /// configuration found; every other parameter is kept.
+    pub fn calibrate(&self, sample: &[u8], target_avg: u64) -> ChunkingConfig {
+        if sample.is_empty() || target_avg == 0 {
+            return self.config.clone();
+        }
+
+        let mut trials = 0;
+        let mut best = (u64::MAX, self.config.clone());
+        let mut measure = |config: ChunkingConfig| -> Option<u64> {
+            if trials == CALIBRATION_MAX_TRIALS || config.validate().is_err() {
+                return None;
+            }
+            trials += 1;
+            let count = SeqChunking::from_config(config.clone()).chunk_all(sample).count() as u64;
+            let avg = sample.len() as u64 / count.max(1);
+            let error = avg.abs_diff(target_avg);
+            if error < best.0 {
+                best = (error, config);
+            }
+            Some(avg)
+        };
+        let close_enough = |avg: u64| avg.abs_diff(target_avg) * 10 <= target_avg;
+
+        let (mut lo, mut hi) = (1, CALIBRATION_MAX_THRESHOLD);
+        while lo <= hi {
+            let mid = lo + (hi - lo) / 2;
+            let Some(avg) = measure(ChunkingConfig { seq_threshold: mid, ..self.config.clone() }) else { break };
+            if close_enough(avg) {
+                return best.1;
+            }
+            if avg < target_avg { lo = mid + 1 } else { hi = mid - 1 }
+        }
+
+        // Thresholds up to `hi` give chunks that are too small; try lowering
+        // the jump trigger for each, largest first
+        for threshold in (1..=hi).rev() {
+            let base = ChunkingConfig { seq_threshold: threshold, ..self.config.clone() };
+            let (mut lo, mut hi) = (1, base.jump_trigger);
+            while lo <= hi {
+                let mid = lo + (hi - lo) / 2;
+                let Some(avg) = measure(ChunkingConfig { jump_trigger: mid, ..base.clone() }) else { return best.1 };
+                if close_enough(avg) {
+                    return best.1;
+                }
+                if avg < target_avg { hi = mid - 1 } else { lo = mid + 1 }
+            }
+        }
+
+        best.1
+    }
+
+    /// Split the data into about `n` content-defined chunks
+    ///
+    /// Block sizes are derived from `data.len() / n`, from half to twice that,
+    /// and the thresholds are then [calibrated](Self::calibrate) on the data
+    /// itself. Where the thresholds cannot reach the target, e.g. on long
+    /// ramps that always cut at `min_block_size`, the block sizes are rescaled
+    /// by the observed count for a few rounds, costing extra chunking passes.
+    /// The count is only close to `n`; boundaries stay content-defined, so
+    /// similar inputs still split alike. Other parameters are kept; an `n` of
+    /// 0 is treated as 1.
+    pub fn chunk_into_n(&self, data: &[u8], n: usize) -> Vec<OwnedChunk> {
+        if data.is_empty() {
+            return Vec::new();
+        }
+
+        let n = n.max(1);
+        let target = (data.len() / n).max(1) as u64;
+        let mut size = target;
+        let mut best: Vec<OwnedChunk> = Vec::new();
+        for _ in 0..CHUNK_INTO_N_ROUNDS {
+            let min = (size / 2).max(self.config.slope_window + 1);
+            let avg = size.max(min);
+            let sized = ChunkingConfig {
+                min_block_size: min,
+                avg_block_size: avg,
+                max_block_size: avg.saturating_mul(2),
+                jump_size: self.config.jump_size.min(min),
+                ..self.config.clone()
+            };
+            let config = SeqChunking::from_config(sized).calibrate(data, target);
+            let chunks = SeqChunking::from_config(config).chunk_all_owned(data);
+
+            let count = chunks.len();
+            if best.is_empty() || count.abs_diff(n) < best.len().abs_diff(n) {
+                best = chunks;
+            }
+            if count.abs_diff(n) * 10 <= n {
+                break;
+            }
+            size = ((size as u128 * count as u128 / n as u128) as u64).max(1);
+        }
+        best
+    }
+
+    /// Chunk the data and collect owned copies of all chunks
+    pub fn chunk_all_owned(&self, data: &[u8]) -> Vec<OwnedChunk> {
+        self.chunk_all_owned_iter(data).collect()
+    }
+
+    /// Consume the data and chunk it in one call, returning owned chunks
+    ///
+    /// The result does not borrow `data`, so it can be returned from the
+    /// function that produced the vector.
+    pub fn into_chunks(data: Vec<u8>, config: ChunkingConfig) -> Vec<OwnedChunk> {
+        Self::from_config(config).chunk_all_owned(&data)
+    }
+
+    /// Lazily iterate over owned copies of the chunks
+    ///
+    /// Each chunk's bytes are copied only when the iterator reaches it.
+    pub fn chunk_all_owned_iter<'a>(&'a self, data: &'a [u8]) -> impl Iterator<Item = OwnedChunk> + 'a {
+        self.chunk_all(data).map(OwnedChunk::from)
+    }
+
+    /// Chunk a [`Bytes`] buffer into slices sharing its allocation (`bytes` feature)
+    ///
+    /// Boundaries are those of [`chunk_all`](Self::chunk_all) on the same
+    /// bytes. They are all found upfront, since the iterator owns the buffer;
+    /// each chunk is a refcounted [`Bytes::slice`] and no data is copied.
+    #[cfg(feature = "bytes")]
+    pub fn chunk_bytes(&self, data: Bytes) -> impl Iterator<Item = BytesChunk> + use<> {
+        let ends = self.chunk_offsets(&data);
+        let mut start = 0;
+        ends.into_iter().map(move |end| {
+            let chunk = BytesChunk { data: data.slice(start..end), start, len: end - start };
+            start = end;
+            chunk
+        })
+    }
+
+    /// Chunk the data, then move each boundary to the strongest slope nearby
+    ///
+    /// Each cut of [`chunk_all`](Self::chunk_all) is moved to the position
+    /// within `refine_window` bytes of it whose slope runs furthest in the
+    /// `op_mode` direction, the nearest one on ties. Steep steps tend to
+    /// survive edits that shift nearby runs, at the cost of a second pass.
+    /// Boundaries are refined in order and each one is kept between
+    /// `min_block_size` and `max_block_size` from the refined start of its
+    /// chunk, so a boundary may be clamped outside the window. As with
+    /// `chunk_all`, only the final chunk may be shorter than `min_block_size`.
+    pub fn chunk_all_refined(&self, data: &[u8], refine_window: usize) -> Vec<OwnedChunk> {
+        let min = self.config.min_block_size as usize;
+        let max = self.config.max_block_size as usize;
+        let strength = |pos: usize| match self.config.op_mode {
+            SeqOpMode::Increasing => self.slope(data, pos),
+            SeqOpMode::Decreasing => -self.slope(data, pos),
+        };
+        // The strongest position in `lo..=hi`, the nearest to `cut` on ties
+        let strongest = |lo: usize, hi: usize, cut: usize| {
+            (lo..=hi)
+                .max_by_key(|&pos| (strength(pos), core::cmp::Reverse(pos.abs_diff(cut))))
+                .unwrap_or(cut)
+        };
+
+        let mut ends = Vec::new();
+        let mut start = 0usize;
+        for cut in self.chunk_offsets(data) {
+            // The final boundary is the end of the data
+            if cut == data.len() || start.saturating_add(min) >= data.len() {
+                break;
+            }
+            let lo = cut.saturating_sub(refine_window).max(start + min);
+            let hi = cut.saturating_add(refine_window).min(start.saturating_add(max)).min(data.len() - 1);
+            let refined = if lo > hi {
+                cut.clamp(start + min, start.saturating_add(max).min(data.len()))
+            } else {
+                strongest(lo, hi, cut)
+            };
+            ends.push(refined);
+            start = refined;
+        }
+        // Boundaries moved back can leave a tail longer than `max_block_size`
+        while data.len() - start > max {
+            let cut = start + max;
+            let refined = strongest(cut.saturating_sub(refine_window).max(start + min), cut, cut);
+            ends.push(refined);
+            start = refined;
+        }
+        if start < data.len() {
+            ends.push(data.len());
+        }
+
+        let mut start = 0;
+        ends.into_iter()
+            .map(|end| {
+                let chunk = OwnedChunk::new(data[start..end].to_vec(), start);
+                start = end;
+                chunk
+            })
+            .collect()
+    }
+
+    /// Chunk the concatenation of `segments` without concatenating them
+    ///
+    /// Boundaries equal those of [`chunk_all`](Self::chunk_all) over the
+    /// concatenated data. A cutpoint never depends on bytes past
+    /// `max_block_size`, so each scan reads a window of that size, borrowed
+    /// from the segment unless it straddles a segment boundary, in which case
+    /// only the window is copied to a scratch buffer. Each chunk's bytes are
+    /// then copied once into its [`OwnedChunk`].
+    pub fn chunk_segments<'a>(&'a self, segments: &'a [&'a [u8]]) -> impl Iterator<Item = OwnedChunk> + 'a {
+        let total: usize = segments.iter().map(|segment| segment.len()).sum();
+        let max = self.config.max_block_size as usize;
+        let mut scratch = Vec::new();
+        let (mut segment, mut offset, mut position) = (0, 0, 0);
+        let mut prev_len = None;
+
+        core::iter::from_fn(move || {
+            if position >= total {
+                return None;
+            }
+            while offset == segments[segment].len() {
+                segment += 1;
+                offset = 0;
+            }
+
+            let remaining = total - position;
+            let window_len = remaining.min(max.saturating_add(1));
+            let current = &segments[segment][offset..];
+            let window = if current.len() >= window_len {
+                &current[..window_len]
+            } else {
+                scratch.clear();
+                gather_segments(segments, (segment, offset), window_len, &mut scratch);
+                &scratch[..]
+            };
+
+            let cut = self.find_cutpoint_at(window, window_len as u64, position) as usize;
+            // Only a FullRemaining fallback cuts past max_block_size, taking everything left
+            let cut = if cut > max { remaining } else { cut };
+            let len = self.smooth_len(cut, prev_len, remaining).clamp(1, remaining);
+
+            let mut data = Vec::with_capacity(len);
+            (segment, offset) = gather_segments(segments, (segment, offset), len, &mut data);
+            let chunk = OwnedChunk::new(data, position);
+            position += len;
+            prev_len = Some(len);
+            Some(chunk)
+        })
+    }
+
+    /// Send each chunk over a channel as soon as its boundary is found
+    ///
+    /// Intended to run on a producer thread while a consumer receives the
+    /// chunks. Chunking stops early once the receiver has been dropped.
+    #[cfg(feature = "std")]
+    pub fn chunk_to_channel(&self, data: Arc<[u8]>, tx: Sender<OwnedChunk>) {
+        for chunk in self.chunk_all_owned_iter(&data) {
+            if tx.send(chunk).is_err() {
+                break;
+            }
+        }
+    }
+
+    /// Iterate over the chunks paired with their CRC-32/ISO-HDLC checksum
+    pub fn chunk_all_with_crc<'a>(&'a self, data: &'a [u8]) -> impl Iterator<Item = (Chunk<'a>, u32)> + 'a {
+        self.chunk_all(data).map(|chunk| {
+            let crc = chunk.crc32();
+            (chunk, crc)
+        })
+    }
+
+    /// Iterate over the chunks paired with their entropy in bits per byte
+    #[cfg(feature = "std")]
+    pub fn chunk_all_with_entropy<'a>(&'a self, data: &'a [u8]) -> impl Iterator<Item = (Chunk<'a>, f64)> + 'a {
+        self.chunk_all(data).map(|chunk| {
+            let entropy = chunk.entropy();
+            (chunk, entropy)
+        })
+    }
+
+    /// Split the data into roughly `k` content-defined shards
+    ///
+    /// The data is chunked with a configuration derived from this chunker's,
+    /// with `avg_block_size` set to `data.len() / k`, `min_block_size` to half
+    /// of that and `max_block_size` to twice that, and with the `MaxBlock`
+    /// fallback and no `max_size_ratio`, so no chunk exceeds that maximum.
+    /// Shard edges are then placed at the chunk boundaries closest to each
+    /// ideal edge `i * data.len() / k`, so they stay content-defined. Every
+    /// chunk boundary is at most one average size away from an ideal edge, so
+    /// at most two ideal edges share a boundary: the result has between
+    /// `k / 2` and `k` shards whenever the data holds at least `k` bytes.
+    /// `k == 0` is treated as 1.
+    pub fn shard(&self, data: &[u8], k: usize) -> Vec<OwnedChunk> {
+        if data.is_empty() {
+            return Vec::new();
+        }
+
+        let k = k.max(1);
+        let target = (data.len() / k).max(1) as u64;
+        let mut config = self.config.clone();
+        config.min_block_size = (target / 2).max(1);
+        config.avg_block_size = target;
+        config.max_block_size = target * 2;
+        config.fast_first_chunk = false;
+        config.fallback = FallbackPolicy::MaxBlock;
+        config.max_size_ratio = None;
+
+        let chunker = SeqChunking::from_config(config);
+        let boundaries: Vec<usize> = chunker.chunk_all(data).map(|c| c.end()).collect();
+
+        let mut edges = vec![0usize];
+        for i in 1..k {
+            let ideal = i * data.len() / k;
+            let idx = boundaries.partition_point(|&b| b < ideal);
+            let candidates = [idx.checked_sub(1), Some(idx)];
+            let nearest = candidates
+                .into_iter()
+                .flatten()
+                .filter_map(|j| boundaries.get(j).copied())
+                .min_by_key(|&b| b.abs_diff(ideal));
+
+            if let Some(edge) = nearest
+                && edge > *edges.last().unwrap_or(&0)
+                && edge < data.len()
+            {
+                edges.push(edge);
+            }
+        }
+        edges.push(data.len());
+
+        edges
+            .windows(2)
+            .map(|w| OwnedChunk::new(data[w[0]..w[1]].to_vec(), w[0]))
+            .collect()
+    }
+
+    /// Chunk the data and pair each owned chunk with its SHA-256 hash
+    #[cfg(feature = "hashing")]
+    pub fn chunk_all_hashed(&self, data: &[u8]) -> Vec<(OwnedChunk, [u8; HASH_SIZE])> {
+        self.chunk_all_owned_iter(data)
+            .map(|chunk| {
+                let hash = chunk.hash();
+                (chunk, hash)
+            })
+            .collect()
+    }
+
+    /// Chunk the data and pair each owned chunk with its ID from `hasher`
+    pub fn chunk_all_hashed_with<H: ChunkHasher>(&self, data: &[u8], hasher: H) -> Vec<(OwnedChunk, Vec<u8>)> {
+        self.chunk_all_owned_iter(data)
+            .map(|chunk| {
+                let id = hasher.hash(&chunk.data);
+                (chunk, id)
+            })
+            .collect()
+    }
+
+    /// Chunk the data, pairing each owned chunk with the hash of the chunk before it
+    ///
+    /// The first chunk is paired with an all-zero hash. Consumers can verify the
+    /// ordering of a chunk sequence pairwise by re-hashing each chunk and
+    /// comparing it with the next chunk's previous hash.
+    #[cfg(feature = "hashing")]
+    pub fn chunk_all_chained<'a>(
+        &'a self,
+        data: &'a [u8],
+    ) -> impl Iterator<Item = (OwnedChunk, [u8; HASH_SIZE])> + 'a {
+        let mut prev_hash = [0u8; HASH_SIZE];
+        self.chunk_all(data).map(move |chunk| {
+            let owned = chunk.to_owned_chunk();
+            let link = prev_hash;
+            prev_hash = owned.hash();
+            (owned, link)
+        })
+    }
+
+    /// Chunk the data and check the chunker's own output for consistency
+    ///
+    /// Verifies that the chunks are contiguous, cover the whole input,
+    /// reconstruct the original bytes, and respect the configured size bounds.
+    /// Only the final chunk may be smaller than `min_block_size`, plus the
+    /// first one when `fast_first_chunk` is enabled.
+    pub fn validate_self(&self, data: &[u8]) -> Result<()> {
+        let chunks: Vec<_> = self.chunk_all(data).collect();
+
+        ValidationUtils::validate_chunk_coverage(data.len(), &chunks)?;
+
+        if !ValidationUtils::verify_chunks(data, &chunks)? {
+            return Err(ChunkingError::processing_error(
+                "Chunks do not reconstruct the original data",
+            ));
+        }
+
+        let max = self.config.max_block_size as usize;
+        let min = self.config.min_block_size as usize;
+        let last = chunks.len().saturating_sub(1);
+
+        for (i, chunk) in chunks.iter().enumerate() {
+            let full_remaining = i == last && self.config.fallback == FallbackPolicy::FullRemaining;
+            if chunk.len > max && !full_remaining {
+                return Err(ChunkingError::processing_error(format!(
+                    "Chunk {} has size {} above max_block_size {}",
+                    i, chunk.len, max
+                )));
+            }
+
+            let fast_first = i == 0 && self.config.fast_first_chunk;
+            if i != last && !fast_first && chunk.len < min {
+                return Err(ChunkingError::processing_error(format!(
+                    "Chunk {} has size {} below min_block_size {}",
+                    i, chunk.len, min
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Calculate chunking statistics for the given data
+    ///
+    /// Empty data gives zeroed statistics that report
+    /// [`is_empty`](ChunkingStats::is_empty); chunking never fails otherwise.
+    pub fn stats(&self, data: &[u8]) -> ChunkingStats {
+        let chunks: Vec<_> = self.chunk_all(data).collect();
+        ChunkingStats::from_chunks(&chunks, data.len())
+    }
+
+    /// Chunking statistics computed without collecting the chunks
+    ///
+    /// Matches [`stats`](Self::stats) except that percentiles are not
+    /// computed; see [`ChunkingStats::from_chunk_iter`].
+    pub fn stats_streaming(&self, data: &[u8]) -> ChunkingStats {
+        ChunkingStats::from_chunk_iter(self.chunk_all(data), data.len())
+    }
+
+    /// Calculate chunking statistics with a histogram of power-of-two size buckets
+    /// between `min_block_size` and `max_block_size`
+    pub fn stats_with_histogram(&self, data: &[u8]) -> ChunkingStats {
+        let chunks: Vec<_> = self.chunk_all(data).collect();
+        let boundaries = ChunkingStats::power_of_two_buckets(
+            self.config.min_block_size as usize,
+            self.config.max_block_size as usize,
+        );
+        ChunkingStats::from_chunks(&chunks, data.len()).with_histogram(&chunks, &boundaries)
+    }
+
+    /// Calculate chunking statistics including the total zstd-compressed size
+    #[cfg(feature = "zstd")]
+    pub fn stats_with_compression(&self, data: &[u8], level: i32) -> ChunkingStats {
+        let chunks: Vec<_> = self.chunk_all(data).collect();
+        ChunkingStats::from_chunks(&chunks, data.len()).with_compressed_size(&chunks, level)
+    }
+
+    /// One record of per-chunk attributes for every chunk, in order
+    pub fn per_chunk_report(&self, data: &[u8]) -> Vec<ChunkRecord> {
+        self.chunk_all(data)
+            .enumerate()
+            .map(|(index, chunk)| ChunkRecord {
+                index,
+                start: chunk.start,
+                len: chunk.len,
+                #[cfg(feature = "std")]
+                entropy: chunk.entropy(),
+                #[cfg(feature = "hashing")]
+                weak_hash: {
+                    let hash = hash_bytes(chunk.data);
+                    u64::from_be_bytes([hash[0], hash[1], hash[2], hash[3], hash[4], hash[5], hash[6], hash[7]])
+                },
+            })
+            .collect()
+    }
+}
+
+/// Attributes of a single chunk, as produced by [`SeqChunking::per_chunk_report`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChunkRecord {
+    /// Position of the chunk in the chunk sequence
+    pub index: usize,
+    /// Offset of the chunk in the input
+    pub start: usize,
+    /// Length of the chunk in bytes
+    pub len: usize,
+    /// Shannon entropy of the chunk's bytes, in bits per byte (0.0 to 8.0)
+    #[cfg(feature = "std")]
+    pub entropy: f64,
+    /// First 8 bytes of the chunk's SHA-256, big-endian; a fingerprint, not for dedup
+    #[cfg(feature = "hashing")]
+    pub weak_hash: u64,
+}
+
+impl<T: SeqElement> Default for SeqChunker<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 64-bit FNV-1a over little-endian words, stable across platforms and releases
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, word: u64) {
+        for byte in word.to_le_bytes() {
+            self.0 = (self.0 ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Square root that also works without `std`
+#[cfg(feature = "std")]
+fn sqrt(x: f64) -> f64 {
+    x.sqrt()
+}
+
+/// Square root that also works without `std`, using Newton's method
+#[cfg(not(feature = "std"))]
+fn sqrt(x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    let mut guess = if x > 1.0 { x / 2.0 } else { 1.0 };
+    for _ in 0..64 {
+        let next = 0.5 * (guess + x / guess);
+        if (next - guess).abs() <= f64::EPSILON * guess {
+            return next;
+        }
+        guess = next;
+    }
+    guess
+}
+
+/// Base-2 logarithm that also works without `std`
+#[cfg(feature = "std")]
+fn log2(x: f64) -> f64 {
+    x.log2()
+}
+
+/// Base-2 logarithm that also works without `std`, for positive normal `x`
+///
+/// Splits off the binary exponent and sums the `atanh` series of the mantissa.
+#[cfg(not(feature = "std"))]
+fn log2(x: f64) -> f64 {
+    let bits = x.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i64 - 1023;
+    let mantissa = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | 0x3ff0_0000_0000_0000);
+
+    let y = (mantissa - 1.0) / (mantissa + 1.0);
+    let (mut term, mut ln) = (y, 0.0);
+    for k in 0..32 {
+        ln += term / (2 * k + 1) as f64;
+        term *= y * y;
+    }
+    exponent as f64 + 2.0 * ln * core::f64::consts::LOG2_E
+}
+
+/// Shannon entropy in bits per byte of a byte histogram totalling `len`
+fn entropy_from_counts(counts: &[u64; 256], len: u64) -> f64 {
+    if len == 0 {
+        return 0.0;
+    }
+    let len = len as f64;
+    counts
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / len;
+            -p * log2(p)
+        })
+        .sum()
+}
+
+/// Append `len` bytes of `segments` from the `(segment, offset)` cursor to `out`
+///
+/// Returns the cursor just past the copied bytes.
+fn gather_segments(segments: &[&[u8]], (mut segment, mut offset): (usize, usize), mut len: usize, out: &mut Vec<u8>) -> (usize, usize) {
+    while len > 0 {
+        let part = &segments[segment][offset..];
+        let take = part.len().min(len);
+        out.extend_from_slice(&part[..take]);
+        len -= take;
+        if take == part.len() {
+            segment += 1;
+            offset = 0;
+        } else {
+            offset += take;
+        }
+    }
+    (segment, offset)
+}
+
+/// Nearest-rank percentile of sorted sizes
+fn percentile(sorted: &[usize], pct: usize) -> usize {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = (pct * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+/// Work done by the cutpoint scans, from [`SeqChunker::chunk_all_instrumented`]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct ScanMetrics {
+    /// Positions whose slope (or Gear hash) was evaluated
+    pub comparisons: u64,
+    /// Jumps taken after `jump_trigger` opposing slopes
+    pub jumps: u64,
+    /// Equal-byte positions absorbed without affecting the sequence
+    pub absorbed_bytes: u64,
+    /// Cuts placed by the fallback policy because no trigger was found
+    pub forced_max_cuts: u64,
+}
+
+/// Why a chunk ends where it does, from [`SeqChunker::chunk_all_with_reasons`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CutReason {
+    /// A content-defined trigger fired: a run reaching `seq_threshold`, or
+    /// another configured trigger such as `alternation_threshold`
+    SeqThreshold,
+    /// No trigger was found, so the fallback policy placed the cut, usually
+    /// at `max_block_size` (or where `max_scan_distance` stopped the scan)
+    MaxBlockSize,
+    /// The data ended before a trigger or `max_block_size` was reached
+    EndOfData,
+    /// `max_size_ratio` moved the cut away from where the scan placed it
+    SizeRatio,
+}
+
+/// Observer recording whether the scans of one chunk found a trigger
+#[derive(Default)]
+struct TriggerObserver {
+    scanned: bool,
+    fell_back: bool,
+}
+
+impl ScanObserver for TriggerObserver {
+    fn scan_finished(&mut self, _comparisons: u64, _absorbed: u64, _jumps: u64) {
+        self.scanned = true;
+    }
+
+    fn fallback_cut(&mut self) {
+        self.fell_back = true;
+    }
+}
+
+/// Receives the work done by each scan; `()` discards it
+trait ScanObserver {
+    fn scan_finished(&mut self, comparisons: u64, absorbed: u64, jumps: u64);
+    fn fallback_cut(&mut self);
+}
+
+impl ScanObserver for () {
+    #[inline(always)]
+    fn scan_finished(&mut self, _comparisons: u64, _absorbed: u64, _jumps: u64) {}
+
+    #[inline(always)]
+    fn fallback_cut(&mut self) {}
+}
+
+impl ScanObserver for ScanMetrics {
+    fn scan_finished(&mut self, comparisons: u64, absorbed: u64, jumps: u64) {
+        self.comparisons += comparisons;
+        self.absorbed_bytes += absorbed;
+        self.jumps += jumps;
+    }
+
+    fn fallback_cut(&mut self) {
+        self.forced_max_cuts += 1;
+    }
+}
+
+/// Statistics about chunking results
+#[derive(Debug, Clone)]
+pub struct ChunkingStats {
+    /// Total number of chunks
+    pub chunk_count: usize,
+    /// Total size of data processed
+    pub total_size: usize,
+    /// Average chunk size
+    pub avg_chunk_size: f64,
+    /// Minimum chunk size
+    pub min_chunk_size: usize,
+    /// Maximum chunk size
+    pub max_chunk_size: usize,
+    /// Standard deviation of chunk sizes
+    pub chunk_size_stddev: f64,
+    /// Median chunk size
+    pub median_chunk_size: usize,
+    /// 90th percentile chunk size
+    pub p90_chunk_size: usize,
+    /// 99th percentile chunk size
+    pub p99_chunk_size: usize,
+    /// Chunk count per size bucket, empty unless computed with [`ChunkingStats::with_histogram`]
+    pub histogram: Vec<(Range<usize>, usize)>,
+    /// Sum of the zstd-compressed sizes of all chunks, if computed
+    #[cfg(feature = "zstd")]
+    pub total_compressed_size: Option<usize>,
+}
+
+impl ChunkingStats {
+    /// Create statistics from a collection of chunks
+    pub fn from_chunks(chunks: &[Chunk<'_>], total_size: usize) -> Self {
+        if chunks.is_empty() {
+            return Self::empty(total_size);
+        }
+
+        let chunk_sizes: Vec<usize> = chunks.iter().map(|c| c.len).collect();
+        let chunk_count = chunks.len();
+        let sum: usize = chunk_sizes.iter().sum();
+        let avg = sum as f64 / chunk_count as f64;
+
+        let min_size = chunk_sizes.iter().min().copied().unwrap_or(0);
+        let max_size = chunk_sizes.iter().max().copied().unwrap_or(0);
+
+        // Calculate standard deviation
+        let variance: f64 = chunk_sizes
+            .iter()
+            .map(|&size| {
+                let diff = size as f64 - avg;
+                diff * diff
+            })
+            .sum::<f64>()
+            / chunk_count as f64;
+
+        let stddev = sqrt(variance);
+
+        let mut sorted_sizes = chunk_sizes;
+        sorted_sizes.sort_unstable();
+
+        Self {
+            chunk_count,
+            total_size,
+            avg_chunk_size: avg,
+            min_chunk_size: min_size,
+            max_chunk_size: max_size,
+            chunk_size_stddev: stddev,
+            median_chunk_size: percentile(&sorted_sizes, 50),
+            p90_chunk_size: percentile(&sorted_sizes, 90),
+            p99_chunk_size: percentile(&sorted_sizes, 99),
+            histogram: Vec::new(),
+            #[cfg(feature = "zstd")]
+            total_compressed_size: None,
+        }
+    }
+
+    /// Create statistics in a single pass over the chunks, without storing them
+    ///
+    /// The variance is accumulated with Welford's algorithm. Percentiles need
+    /// every chunk size, so `median_chunk_size`, `p90_chunk_size` and
+    /// `p99_chunk_size` are left at 0.
+    pub fn from_chunk_iter<'a>(chunks: impl IntoIterator<Item = Chunk<'a>>, total_size: usize) -> Self {
+        let mut stats = Self::empty(total_size);
+        let mut mean = 0.0;
+        let mut squared_diffs = 0.0;
+
+        for chunk in chunks {
+            stats.chunk_count += 1;
+            stats.min_chunk_size = if stats.chunk_count == 1 { chunk.len } else { stats.min_chunk_size.min(chunk.len) };
+            stats.max_chunk_size = stats.max_chunk_size.max(chunk.len);
+
+            let size = chunk.len as f64;
+            let delta = size - mean;
+            mean += delta / stats.chunk_count as f64;
+            squared_diffs += delta * (size - mean);
+        }
+
+        if stats.chunk_count > 0 {
+            stats.avg_chunk_size = mean;
+            stats.chunk_size_stddev = sqrt(squared_diffs / stats.chunk_count as f64);
+        }
+        stats
+    }
+
+    /// Whether no chunks were counted, i.e. the input was empty
+    ///
+    /// The averages and percentiles of empty statistics are 0 rather than
+    /// undefined, so check this before interpreting them.
+    pub fn is_empty(&self) -> bool {
+        self.chunk_count == 0
+    }
+
+    fn empty(total_size: usize) -> Self {
+        Self {
+            chunk_count: 0,
+            total_size,
+            avg_chunk_size: 0.0,
+            min_chunk_size: 0,
+            max_chunk_size: 0,
+            chunk_size_stddev: 0.0,
+            median_chunk_size: 0,
+            p90_chunk_size: 0,
+            p99_chunk_size: 0,
+            histogram: Vec::new(),
+            #[cfg(feature = "zstd")]
+            total_compressed_size: None,
+        }
+    }
+
+    /// Count chunks into size buckets delimited by the given boundaries
+    ///
+    /// Boundaries `[b0, b1, ..., bn]` produce the buckets `0..b0`, `b0..b1`,
+    /// ..., `bn..usize::MAX`, so every chunk falls into exactly one bucket.
+    /// Empty leading buckets (e.g. `0..0`) are omitted.
+    pub fn with_histogram(mut self, chunks: &[Chunk<'_>], boundaries: &[usize]) -> Self {
+        let mut edges = vec![0];
+        edges.extend(boundaries.iter().copied().filter(|&b| b > 0));
+        edges.sort_unstable();
+        edges.dedup();
+        edges.push(usize::MAX);
+
+        let mut histogram: Vec<(Range<usize>, usize)> =
+            edges.windows(2).map(|w| (w[0]..w[1], 0)).collect();
+
+        let last = histogram.len() - 1;
+        for chunk in chunks {
+            let bucket = edges.partition_point(|&edge| edge <= chunk.len) - 1;
+            histogram[bucket.min(last)].1 += 1;
+        }
+
+        self.histogram = histogram;
+        self
+    }
+
+    /// Bucket boundaries at `min`, every power of two strictly between `min` and `max`, and `max`
+    pub fn power_of_two_buckets(min: usize, max: usize) -> Vec<usize> {
+        let mut boundaries = vec![min];
+        let mut edge = min.max(1).next_power_of_two();
+        while edge < max {
+            if edge > min {
+                boundaries.push(edge);
+            }
+            edge *= 2;
+        }
+        if max > min {
+            boundaries.push(max);
+        }
+        boundaries
+    }
+
+    /// Compress every chunk at the given level and record the total compressed size
+    #[cfg(feature = "zstd")]
+    pub fn with_compressed_size(mut self, chunks: &[Chunk<'_>], level: i32) -> Self {
+        self.total_compressed_size = Some(chunks.iter().map(|c| c.compressed_size(level)).sum());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SeqOpMode;
+
+    const CANARY_CHUNK_COUNT: usize = 147;
+    const CANARY_BOUNDARY_HASH: u64 = 0xe1d3_d16c_5327_7ed6;
+    use crate::{
+        DEFAULT_AVG_BLOCK_SIZE, DEFAULT_JUMP_SIZE, DEFAULT_JUMP_TRIGGER, DEFAULT_MAX_BLOCK_SIZE,
+        DEFAULT_MIN_BLOCK_SIZE, DEFAULT_SEQ_THRESHOLD, DEFAULT_SLOPE_WINDOW,
+    };
+
+    #[test]
+    fn test_seq_chunking_new() {
+        let chunker = SeqChunking::new();
+        assert_eq!(chunker.technique_name(), "Seq Chunking");
+        assert_eq!(chunker.config().seq_threshold, 5);
+    }
+
+    #[test]
+    fn test_const_chunker() {
+        static CHUNKER: SeqChunking = SeqChunking::from_config(ChunkingConfig::const_new(
+            DEFAULT_SEQ_THRESHOLD,
+            DEFAULT_JUMP_TRIGGER,
+            DEFAULT_JUMP_SIZE,
+            SeqOpMode::Increasing,
+            DEFAULT_MIN_BLOCK_SIZE,
+            DEFAULT_AVG_BLOCK_SIZE,
+            DEFAULT_MAX_BLOCK_SIZE,
+        ));
+
+        assert_eq!(CHUNKER.technique_name(), "Seq Chunking");
+        assert_eq!(CHUNKER.algorithm_fingerprint(), SeqChunking::new().algorithm_fingerprint());
+    }
+
+    #[test]
+    fn test_seq_chunking_from_config() {
+        let config = ChunkingConfig::builder().seq_threshold(10).build().unwrap();
+        let chunker = SeqChunking::from_config(config);
+        assert_eq!(chunker.config().seq_threshold, 10);
+    }
+
+    #[test]
+    fn test_find_cutpoint_small_size() {
+        let chunker = SeqChunking::new();
+        let data = vec![1, 2, 3, 4, 5];
+        let result = chunker.find_cutpoint(&data, 512);
+        assert_eq!(result, 5);
+    }
+
+    #[test]
+    fn test_algorithm_fingerprint() {
+        let fingerprint = |builder: crate::config::ChunkingConfigBuilder| {
+            SeqChunking::from_config(builder.build().unwrap()).algorithm_fingerprint()
+        };
+        let base = SeqChunking::new().algorithm_fingerprint();
+        // Pinned so accidental changes to the hashing scheme are caught
+        assert_eq!(base, 0x67f2_0ac7_4a44_c530);
+
+        // Boundary-equivalent configurations
+        assert_eq!(fingerprint(ChunkingConfig::builder()), base);
+        assert_eq!(
+            fingerprint(ChunkingConfig::builder().seq_threshold_relaxed(Some(DEFAULT_SEQ_THRESHOLD))),
+            base
+        );
+        assert_eq!(fingerprint(ChunkingConfig::builder().avg_block_size(6000)), base);
+        assert_eq!(fingerprint(ChunkingConfig::builder().suppress_window(100)), base);
+
+        // Boundary-affecting changes
+        assert_ne!(fingerprint(ChunkingConfig::builder().seq_threshold(6)), base);
+        assert_ne!(fingerprint(ChunkingConfig::builder().op_mode(SeqOpMode::Decreasing)), base);
+        assert_ne!(fingerprint(ChunkingConfig::builder().normalization_level(1)), base);
+        assert_ne!(
+            fingerprint(ChunkingConfig::builder().normalization_level(1).avg_block_size(6000)),
+            fingerprint(ChunkingConfig::builder().normalization_level(1))
+        );
+    }
+
+    #[test]
+    fn test_chunk_all_from() {
+        use crate::utils::{TestDataGenerator, ValidationUtils};
+
+        let data = TestDataGenerator::generate_pseudo_random(200_000, 53);
+        let config = ChunkingConfig::builder().fast_first_chunk(true).build().unwrap();
+        let chunker = SeqChunking::from_config(config);
+
+        // Resuming at a boundary reproduces the rest of a full pass
+        let full: Vec<_> = chunker.chunk_all(&data).collect();
+        let base = full[3].start;
+        let resumed: Vec<_> = chunker.chunk_all_from(&data[base..], base).collect();
+        assert_eq!(resumed, full[3..]);
+
+        ValidationUtils::validate_chunk_coverage_from(base, data.len() - base, &resumed).unwrap();
+        assert!(ValidationUtils::validate_chunk_coverage(data.len() - base, &resumed).is_err());
+    }
+
+    #[test]
+    fn test_tail_lengths_around_min_block_size() {
+        use crate::utils::TestDataGenerator;
+
+        let chunker = SeqChunking::new();
+        let min = DEFAULT_MIN_BLOCK_SIZE as usize;
+
+        for len in [1, min - 1, min, min + 1] {
+            for data in [vec![0u8; len], TestDataGenerator::generate_pseudo_random(len, 3)] {
+                let chunks: Vec<_> = chunker.chunk_all(&data).collect();
+                assert!(chunks.iter().all(|c| !c.is_empty()), "len {}", len);
+                let reconstructed: Vec<u8> = chunks.iter().flat_map(|c| c.data.iter().copied()).collect();
+                assert_eq!(reconstructed, data, "len {}", len);
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_cutpoint_size_beyond_buffer() {
+        let config = ChunkingConfig::builder()
+            .min_block_size(64)
+            .max_block_size(4096)
+            .jump_trigger(1)
+            .jump_size(64)
+            .op_mode(SeqOpMode::Decreasing)
+            .build()
+            .unwrap();
+        let chunker = SeqChunking::from_config(config);
+
+        // Strictly increasing data jumps on every opposing slope, landing exactly on the end
+        let data: Vec<u8> = (0..=255).collect();
+        assert_eq!(chunker.find_cutpoint(&data, 10_000), data.len() as u64);
+
+        let flat = vec![9u8; 1000];
+        assert_eq!(chunker.find_cutpoint(&flat, 10_000), flat.len() as u64);
+        assert_eq!(chunker.find_cutpoint(&flat[..10], 10_000), 10);
+    }
+
+    #[test]
+    fn test_find_cutpoint_increasing() {
+        let chunker = SeqChunking::new();
+        let mut data = vec![0u8; 8192];
+
+        for (i, byte) in data[4096..4110].iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+
+        let result = chunker.find_cutpoint(&data, 8192);
+        assert!(result < 8192);
+        assert!(result > 4096);
+    }
+
+    #[test]
+    fn test_chunk_iterator() {
+        let chunker = SeqChunking::new();
+        let data = b"Hello, World! This is a test.";
+
+        let chunks: Vec<_> = chunker.chunk_all(data).collect();
+
+        assert!(!chunks.is_empty());
+
+        // Verify reconstruction
+        let reconstructed: Vec<u8> = chunks
+            .iter()
+            .flat_map(|chunk| chunk.data.iter())
+            .copied()
+            .collect();
+
+        assert_eq!(reconstructed, data);
+    }
+
+    #[test]
+    fn test_chunk_all_with_reasons() {
+        use crate::utils::TestDataGenerator;
+
+        let chunker = SeqChunking::new();
+        let data = TestDataGenerator::generate_pseudo_random(1 << 20, 43);
+        let with_reasons: Vec<_> = chunker.chunk_all_with_reasons(&data).collect();
+        assert!(with_reasons.iter().map(|(chunk, _)| chunk.clone()).eq(chunker.chunk_all(&data)));
+        let count = |wanted| with_reasons.iter().filter(|&&(_, reason)| reason == wanted).count();
+        assert!(count(CutReason::SeqThreshold) * 10 > with_reasons.len() * 9);
+        let (_, metrics) = chunker.chunk_all_instrumented(&data);
+        assert!(count(CutReason::MaxBlockSize) as u64 <= metrics.forced_max_cuts);
+        assert_eq!(with_reasons.last().unwrap().1, CutReason::EndOfData);
+
+        // Flat data is only cut at max_block_size, then its short tail at the end
+        let max = chunker.max_block_size() as usize;
+        let flat = vec![0u8; 3 * max + 100];
+        let reasons: Vec<CutReason> = chunker.chunk_all_with_reasons(&flat).map(|(_, reason)| reason).collect();
+        assert_eq!(reasons, [CutReason::MaxBlockSize, CutReason::MaxBlockSize, CutReason::MaxBlockSize, CutReason::EndOfData]);
+
+        // Input shorter than min_block_size is never scanned
+        let short: Vec<_> = chunker.chunk_all_with_reasons(&data[..100]).collect();
+        assert_eq!(short.len(), 1);
+        assert_eq!(short[0].1, CutReason::EndOfData);
+        assert_eq!(chunker.chunk_all_with_reasons(&[]).count(), 0);
+
+        // Cuts moved by smoothing are forced, not content-defined
+        let config = ChunkingConfig::builder().max_size_ratio(Some(1.5)).build().unwrap();
+        let smoothed = SeqChunking::from_config(config);
+        let mut mixed = TestDataGenerator::generate_pseudo_random(200_000, 19);
+        mixed.extend(core::iter::repeat_n(9u8, 100_000));
+        mixed.extend(TestDataGenerator::generate_pseudo_random(200_000, 20));
+        let with_reasons: Vec<_> = smoothed.chunk_all_with_reasons(&mixed).collect();
+        assert!(with_reasons.iter().map(|(chunk, _)| chunk.clone()).eq(smoothed.chunk_all(&mixed)));
+        let plain = SeqChunking::new();
+        let mut moved = 0;
+        for (chunk, reason) in &with_reasons {
+            let natural = plain.find_cutpoint(&mixed[chunk.start..], (mixed.len() - chunk.start) as u64);
+            if chunk.len as u64 != natural.min((mixed.len() - chunk.start) as u64) {
+                assert_eq!(*reason, CutReason::SizeRatio, "chunk at {}", chunk.start);
+                moved += 1;
+            } else {
+                assert_ne!(*reason, CutReason::SizeRatio, "chunk at {}", chunk.start);
+            }
+        }
+        assert!(moved > 0);
+    }
+
+    #[test]
+    fn test_chunking_stats() {
+        let chunker = SeqChunking::new();
+        let data = vec![42u8; 10000];
+
+        let stats = chunker.stats(&data);
+        assert_eq!(stats.total_size, 10000);
+        assert!(stats.chunk_count > 0);
+        assert!(stats.avg_chunk_size > 0.0);
+    }
+
+    #[test]
+    fn test_decreasing_mode() {
+        let config = ChunkingConfig::builder()
+            .op_mode(SeqOpMode::Decreasing)
+            .build()
+            .unwrap();
+        let chunker = SeqChunking::from_config(config);
+
+        let mut data = vec![255u8; 8192];
+        for (i, byte) in data[4096..4110].iter_mut().enumerate() {
+            *byte = (255 - i) as u8;
+        }
+
+        let result = chunker.find_cutpoint(&data, 8192);
+        assert!(result < 8192);
+        assert!(result > 4096);
+    }
+
+    #[test]
+    fn test_chunk_properties() {
+        let data = b"test data";
+        let chunk = Chunk::new(data, 0, data.len());
+
+        assert_eq!(chunk.start, 0);
+        assert_eq!(chunk.len, data.len());
+        assert_eq!(chunk.end(), data.len());
+        assert_eq!(chunk.range(), 0..data.len());
+        assert!(!chunk.is_empty());
+        assert_eq!(chunk.data, data);
+
+        let owned = OwnedChunk::new(data[5..].to_vec(), 5);
+        assert_eq!(&data[owned.range()], owned.data.as_slice());
+    }
+
+    #[test]
+    fn test_validate_self() {
+        use crate::utils::TestDataGenerator;
+
+        let chunker = SeqChunking::new();
+        chunker.validate_self(&[]).unwrap();
+        chunker.validate_self(b"short input").unwrap();
+        chunker
+            .validate_self(&TestDataGenerator::generate_pseudo_random(200_000, 3))
+            .unwrap();
+        chunker
+            .validate_self(&TestDataGenerator::generate_mixed_patterns(200_000))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_normalization_tightens_distribution() {
+        use crate::utils::TestDataGenerator;
+
+        let data = TestDataGenerator::generate_pseudo_random(2_000_000, 1);
+        let stats_for = |level| {
+            let config = ChunkingConfig::builder()
+                .normalization_level(level)
+                .build()
+                .unwrap();
+            SeqChunking::from_config(config).stats(&data)
+        };
+
+        let plain = stats_for(0);
+        let normalized = stats_for(2);
+        assert!(normalized.chunk_size_stddev < plain.chunk_size_stddev / 2.0);
+
+        let avg = DEFAULT_AVG_BLOCK_SIZE as f64;
+        assert!((normalized.avg_chunk_size - avg).abs() < (plain.avg_chunk_size - avg).abs());
+    }
+
+    #[test]
+    fn test_u16_elements() {
+        let config = ChunkingConfig::builder()
+            .min_block_size(64)
+            .max_block_size(1024)
+            .build()
+            .unwrap();
+        let chunker: SeqChunker<u16> = SeqChunker::from_config(config);
+
+        // Slopes between samples are far beyond the u8 range
+        let mut data = vec![40_000u16; 4096];
+        for (i, sample) in data[100..110].iter_mut().enumerate() {
+            *sample = 1_000 * i as u16;
+        }
+
+        let first = chunker.chunk_first(&data).unwrap();
+        assert!(first.len > 100 && first.len < 110);
+
+        let total: usize = chunker.chunk_all(&data).map(|c| c.len).sum();
+        assert_eq!(total, data.len());
+    }
+
+    #[test]
+    fn test_fast_first_chunk() {
+        use crate::utils::TestDataGenerator;
+
+        let data = TestDataGenerator::generate_pseudo_random(100_000, 11);
+        let config = ChunkingConfig::builder()
+            .fast_first_chunk(true)
+            .build()
+            .unwrap();
+        let chunker = SeqChunking::from_config(config);
+
+        let chunks = chunker.chunk_all_vec(&data);
+        assert!(chunks[0].len < DEFAULT_MIN_BLOCK_SIZE as usize);
+        assert!(chunks[0].len >= FAST_FIRST_CHUNK_MIN_SIZE as usize);
+        assert!(chunks[1..chunks.len() - 1]
+            .iter()
+            .all(|c| c.len >= DEFAULT_MIN_BLOCK_SIZE as usize));
+        chunker.validate_self(&data).unwrap();
+
+        let plain = SeqChunking::new();
+        assert!(plain.chunk_first(&data).unwrap().len >= DEFAULT_MIN_BLOCK_SIZE as usize);
+    }
+
+    #[test]
+    fn test_first_diff_transform() {
+        // Decreasing convex quadratic: raw slopes all fall, first differences all rise
+        let mut data = vec![0u8; 64];
+        for (i, byte) in data[16..32].iter_mut().enumerate() {
+            *byte = ((15 - i) * (15 - i)) as u8;
+        }
+        let chunker_for = |transform| {
+            let config = ChunkingConfig::builder()
+                .min_block_size(16)
+                .max_block_size(64)
+                .transform(transform)
+                .build()
+                .unwrap();
+            SeqChunking::from_config(config)
+        };
+
+        assert_eq!(chunker_for(SlopeTransform::Raw).find_cutpoint(&data, 64), 64);
+        assert!(chunker_for(SlopeTransform::FirstDiff).find_cutpoint(&data, 64) < 32);
+    }
+
+    #[test]
+    fn test_second_diff_transform() {
+        // Decreasing cubic ramp whose first differences keep falling while the
+        // second differences rise
+        let data: Vec<i32> = (0..64).map(|i: i32| i * i * i - 40 * i * i).collect();
+        let chunker_for = |transform| {
+            let config = ChunkingConfig::builder()
+                .min_block_size(2)
+                .max_block_size(12)
+                .transform(transform)
+                .build()
+                .unwrap();
+            SeqChunker::<i32>::from_config(config)
+        };
+
+        assert_eq!(chunker_for(SlopeTransform::Raw).find_cutpoint(&data, 12), 12);
+        assert_eq!(chunker_for(SlopeTransform::FirstDiff).find_cutpoint(&data, 12), 12);
+        // Needs three warmup elements before the first slope, then five rising slopes
+        assert_eq!(chunker_for(SlopeTransform::SecondDiff).find_cutpoint(&data, 12), 7);
+    }
+
+    #[test]
+    fn test_slope_max_magnitude() {
+        // Alternating extremes give the largest possible steps in every transform
+        let extremes = |transform, slope_window| {
+            ChunkingConfig::builder()
+                .min_block_size(4)
+                .max_block_size(64)
+                .transform(transform)
+                .slope_window(slope_window)
+                .build()
+                .unwrap()
+        };
+        let max_step = u32::MAX as i64;
+
+        for slope_window in [1, 3] {
+            let unsigned: Vec<u32> = (0..64).map(|i| if (i / slope_window) % 2 == 0 { 0 } else { u32::MAX }).collect();
+            let signed: Vec<i32> = (0..64).map(|i| if (i / slope_window) % 2 == 0 { i32::MIN } else { i32::MAX }).collect();
+            let pos = 4 * slope_window as usize;
+
+            for (transform, factor) in [(SlopeTransform::Raw, 1), (SlopeTransform::FirstDiff, 2), (SlopeTransform::SecondDiff, 4)] {
+                let config = extremes(transform, slope_window);
+                let unsigned_chunker = SeqChunker::<u32>::from_config(config.clone());
+                let signed_chunker = SeqChunker::<i32>::from_config(config);
+
+                assert_eq!(unsigned_chunker.slope(&unsigned, pos).abs(), factor * max_step);
+                assert_eq!(signed_chunker.slope(&signed, pos).abs(), factor * max_step);
+                assert_eq!(unsigned_chunker.chunk_all(&unsigned).map(|c| c.len).sum::<usize>(), 64);
+                assert_eq!(signed_chunker.chunk_all(&signed).map(|c| c.len).sum::<usize>(), 64);
+            }
+        }
+    }
+
+    #[test]
+    fn test_percentiles() {
+        let data: Vec<u8> = vec![0; 100];
+        let chunks: Vec<_> = (0..100)
+            .map(|i| Chunk::new(&data[..i + 1], 0, i + 1))
+            .collect();
+        let stats = ChunkingStats::from_chunks(&chunks, 0);
+
+        assert_eq!(stats.median_chunk_size, 50);
+        assert_eq!(stats.p90_chunk_size, 90);
+        assert_eq!(stats.p99_chunk_size, 99);
+    }
+
+    #[test]
+    fn test_histogram() {
+        assert_eq!(
+            ChunkingStats::power_of_two_buckets(4096, 16384),
+            vec![4096, 8192, 16384]
+        );
+        assert_eq!(
+            ChunkingStats::power_of_two_buckets(3000, 10000),
+            vec![3000, 4096, 8192, 10000]
+        );
+
+        let chunker = SeqChunking::new();
+        let data = crate::utils::TestDataGenerator::generate_pseudo_random(1_000_000, 8);
+        let stats = chunker.stats_with_histogram(&data);
+
+        let buckets: Vec<_> = stats.histogram.iter().map(|(r, _)| r.clone()).collect();
+        assert_eq!(buckets, vec![0..4096, 4096..8192, 8192..16384, 16384..usize::MAX]);
+
+        let counted: usize = stats.histogram.iter().map(|(_, count)| count).sum();
+        assert_eq!(counted, stats.chunk_count);
+        assert!(stats.histogram[1].1 > 0);
+        assert!(stats.median_chunk_size >= stats.min_chunk_size);
+        assert!(stats.p99_chunk_size <= stats.max_chunk_size);
+    }
+
+    #[test]
+    fn test_chunk_all_owned_iter() {
+        use crate::utils::TestDataGenerator;
+
+        let chunker = SeqChunking::new();
+        let data = TestDataGenerator::generate_pseudo_random(100_000, 13);
+
+        let lazy: Vec<OwnedChunk> = chunker.chunk_all_owned_iter(&data).collect();
+        assert_eq!(lazy, chunker.chunk_all_owned(&data));
+
+        let first = chunker.chunk_all_owned_iter(&data).next().unwrap();
+        assert_eq!(first, chunker.chunk_first(&data).unwrap().to_owned_chunk());
+    }
+
+    #[test]
+    fn test_into_chunks() {
+        use crate::utils::{TestDataGenerator, reconstruct};
+
+        // The chunks outlive the vector they were cut from
+        fn load(seed: u64) -> Vec<OwnedChunk> {
+            let data = TestDataGenerator::generate_pseudo_random(100_000, seed);
+            SeqChunking::into_chunks(data, ChunkingConfig::default())
+        }
+
+        let chunks = load(14);
+        let data = TestDataGenerator::generate_pseudo_random(100_000, 14);
+        assert_eq!(chunks, SeqChunking::new().chunk_all_owned(&data));
+        assert_eq!(reconstruct(&chunks), data);
+        assert!(SeqChunking::into_chunks(Vec::new(), ChunkingConfig::default()).is_empty());
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn test_block_scan_matches_scalar() {
+        use crate::utils::TestDataGenerator;
+
+        let mut data = TestDataGenerator::generate_pseudo_random(200_000, 15);
+        data.extend(TestDataGenerator::generate_mixed_patterns(100_000));
+        data.extend(core::iter::repeat_n(7u8, 20_000));
+        data.extend((0..50_000u32).map(|i| (i / 3) as u8));
+
+        for seed in 0..200 {
+            let mut config = ChunkingConfig::random_valid(seed);
+            if seed % 2 == 0 {
+                // Exercise the vectorized raw path more often
+                config.transform = SlopeTransform::Raw;
+                config.slope_window = 1;
+            }
+            let block = SeqChunking::from_config(config.clone());
+            let mut scalar = SeqChunking::from_config(config);
+            scalar.block_scan = false;
+
+            assert_eq!(block.chunk_offsets(&data), scalar.chunk_offsets(&data), "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn test_min_chunk_entropy() {
+        use crate::utils::TestDataGenerator;
+
+        // Rising runs of ten values cut right after min_block_size, but only
+        // carry log2(10) bits per byte
+        let uniform: Vec<u8> = (0..200_000).map(|i| (i % 10) as u8).collect();
+        let plain = SeqChunking::new();
+        let gated = SeqChunking::from_config(ChunkingConfig::builder().min_chunk_entropy(Some(4.0)).build().unwrap());
+
+        let plain_chunks: Vec<_> = plain.chunk_all(&uniform).collect();
+        let gated_chunks: Vec<_> = gated.chunk_all(&uniform).collect();
+        assert!(plain_chunks.len() > 40);
+        assert_eq!(gated_chunks.len(), uniform.len().div_ceil(DEFAULT_MAX_BLOCK_SIZE as usize));
+        assert!(gated_chunks.iter().rev().skip(1).all(|c| c.len == DEFAULT_MAX_BLOCK_SIZE as usize));
+
+        // High-entropy data clears the gate at the first cut
+        let random = TestDataGenerator::generate_pseudo_random(200_000, 16);
+        assert_eq!(plain.chunk_offsets(&random), gated.chunk_offsets(&random));
+
+        let mut ten_values = [0; 256];
+        ten_values[..10].fill(1);
+        assert!((entropy_from_counts(&ten_values, 10) - core::f64::consts::LOG2_10).abs() < 1e-9);
+        assert!((entropy_from_counts(&[3; 256], 768) - 8.0).abs() < 1e-9);
+        assert!(ChunkingConfig::builder().min_chunk_entropy(Some(8.5)).build().is_err());
+
+        // Wide elements count every byte: the low bytes of these u16 values
+        // are all zero, but their high bytes are well spread
+        let wide: Vec<u16> = random.iter().map(|&byte| (byte as u16) << 8).collect();
+        let plain = SeqChunker::<u16>::new();
+        let gated = SeqChunker::<u16>::from_config(ChunkingConfig::builder().min_chunk_entropy(Some(3.0)).build().unwrap());
+        assert_eq!(plain.chunk_offsets(&wide), gated.chunk_offsets(&wide));
+    }
+
+    #[test]
+    fn test_small_input_policy() {
+        let data = vec![1u8; 1000];
+
+        let lenient = SeqChunking::new();
+        let chunks: Vec<_> = lenient.try_chunk_all(&data).unwrap().collect();
+        assert_eq!(chunks.len(), 1);
+
+        let config = ChunkingConfig::builder().small_input_policy(SmallInputPolicy::Error).build().unwrap();
+        let strict = SeqChunking::from_config(config);
+        assert!(matches!(strict.try_chunk_all(&data), Err(ChunkingError::InvalidInput(_))));
+        assert_eq!(strict.try_chunk_all(&[]).unwrap().count(), 0);
+
+        let large = vec![1u8; DEFAULT_MIN_BLOCK_SIZE as usize];
+        assert_eq!(strict.try_chunk_all(&large).unwrap().count(), 1);
+        // The plain iterator is unaffected by the policy
+        assert_eq!(strict.chunk_all(&data).count(), 1);
+    }
+
+    #[test]
+    fn test_calibrate() {
+        use crate::utils::TestDataGenerator;
+
+        let average = |config: &ChunkingConfig, data: &[u8]| {
+            data.len() as u64 / SeqChunking::from_config(config.clone()).chunk_all(data).count() as u64
+        };
+        let chunker = SeqChunking::new();
+
+        // Mixed patterns only reach the target once jumps are tuned too
+        let mixed = TestDataGenerator::generate_mixed_patterns(1_000_000);
+        let target = 12_000;
+        let calibrated = chunker.calibrate(&mixed, target);
+        let default_error = average(chunker.config(), &mixed).abs_diff(target);
+        let calibrated_error = average(&calibrated, &mixed).abs_diff(target);
+        assert!(calibrated_error < default_error);
+        assert!(calibrated_error * 10 <= target, "{:?}", calibrated);
+        assert_eq!(calibrated.min_block_size(), chunker.config().min_block_size());
+
+        let random = TestDataGenerator::generate_pseudo_random(1_000_000, 3);
+        let calibrated = chunker.calibrate(&random, 14_000);
+        assert!(average(&calibrated, &random).abs_diff(14_000) * 10 <= 14_000);
+
+        assert_eq!(chunker.calibrate(&[], 14_000).seq_threshold(), chunker.config().seq_threshold());
+    }
+
+    #[test]
+    fn test_crc32() {
+        use crate::utils::TestDataGenerator;
+
+        // Standard check value of CRC-32/ISO-HDLC
+        assert_eq!(Chunk::new(b"123456789", 0, 9).crc32(), 0xcbf4_3926);
+        assert_eq!(Chunk::new(b"", 0, 0).crc32(), 0);
+        assert_eq!(Chunk::new(b"The quick brown fox jumps over the lazy dog", 0, 43).crc32(), 0x414f_a339);
+
+        let data = TestDataGenerator::generate_pseudo_random(100_000, 17);
+        let chunker = SeqChunking::new();
+        for (chunk, crc) in chunker.chunk_all_with_crc(&data) {
+            assert_eq!(crc, crc32(chunk.data));
+        }
+        assert_eq!(chunker.chunk_all_with_crc(&data).count(), chunker.chunk_all(&data).count());
+    }
+
+    #[test]
+    fn test_chunk_all_hashed_with() {
+        use crate::utils::TestDataGenerator;
+
+        // Stub ID: the chunk's length and first byte
+        struct LenHasher;
+        impl ChunkHasher for LenHasher {
+            fn hash(&self, data: &[u8]) -> Vec<u8> {
+                let mut id = (data.len() as u32).to_be_bytes().to_vec();
+                id.push(data[0]);
+                id
+            }
+            fn id_len(&self) -> usize {
+                5
+            }
+        }
+
+        let data = TestDataGenerator::generate_pseudo_random(100_000, 18);
+        let chunker = SeqChunking::new();
+        let hashed = chunker.chunk_all_hashed_with(&data, &LenHasher);
+        assert_eq!(hashed.len(), chunker.chunk_all(&data).count());
+        for (chunk, id) in &hashed {
+            assert_eq!(id.len(), LenHasher.id_len());
+            assert_eq!(id[..4], (chunk.len as u32).to_be_bytes());
+            assert_eq!(id[4], chunk.data[0]);
+        }
+
+        #[cfg(feature = "hashing")]
+        {
+            let sha = chunker.chunk_all_hashed_with(&data, crate::hasher::Sha256Hasher);
+            let expected = chunker.chunk_all_hashed(&data);
+            assert!(sha.iter().zip(&expected).all(|((_, id), (_, hash))| id[..] == hash[..]));
+        }
+    }
+
+    #[test]
+    fn test_max_size_ratio() {
+        use crate::utils::TestDataGenerator;
+
+        // Quick slope cuts in random data, max-size fallbacks in a flat run
+        let mut data = TestDataGenerator::generate_pseudo_random(200_000, 19);
+        data.extend(core::iter::repeat_n(9u8, 100_000));
+        data.extend(TestDataGenerator::generate_pseudo_random(200_000, 20));
+
+        let plain: Vec<usize> = SeqChunking::new().chunk_all(&data).map(|c| c.len).collect();
+        assert!(plain.windows(2).any(|w| w[1] > 2 * w[0]));
+
+        let config = ChunkingConfig::builder().max_size_ratio(Some(1.5)).build().unwrap();
+        let chunker = SeqChunking::from_config(config);
+        let chunks: Vec<_> = chunker.chunk_all(&data).collect();
+        let sizes: Vec<usize> = chunks.iter().map(|c| c.len).collect();
+
+        // Every chunk but the last is within 1.5x of its predecessor
+        for pair in sizes[..sizes.len() - 1].windows(2) {
+            assert!(pair[1] as f64 <= pair[0] as f64 * 1.5, "{:?}", pair);
+            assert!(pair[1] as f64 >= pair[0] as f64 / 1.5, "{:?}", pair);
+        }
+        assert!(sizes.iter().all(|&len| len <= DEFAULT_MAX_BLOCK_SIZE as usize));
+        ValidationUtils::validate_chunk_coverage(data.len(), &chunks).unwrap();
+
+
+        // Smoothing a FullRemaining fallback must not leave a non-final chunk over max
+        let config = ChunkingConfig::builder()
+            .fallback(FallbackPolicy::FullRemaining)
+            .max_size_ratio(Some(2.5))
+            .build()
+            .unwrap();
+        let mut data = TestDataGenerator::generate_pseudo_random(100_000, 21);
+        data.extend(core::iter::repeat_n(7u8, 200_000));
+        let full_remaining = SeqChunking::from_config(config);
+        let chunks: Vec<_> = full_remaining.chunk_all(&data).collect();
+        assert!(chunks[..chunks.len() - 1].iter().all(|c| c.len <= DEFAULT_MAX_BLOCK_SIZE as usize));
+        ValidationUtils::validate_chunk_coverage(data.len(), &chunks).unwrap();
+
+        assert!(ChunkingConfig::builder().max_size_ratio(Some(0.5)).build().is_err());
+    }
+
+    #[test]
+    fn test_chunk_all_reverse() {
+        use crate::utils::TestDataGenerator;
+
+        let data = TestDataGenerator::generate_pseudo_random(200_000, 25);
+        let chunker = SeqChunking::new();
+        let chunks = chunker.chunk_all_reverse(&data);
+        ValidationUtils::validate_chunk_coverage(data.len(), &chunks).unwrap();
+        assert!(chunks.windows(2).all(|w| w[0].end() == w[1].start));
+
+        // Mirrors forward chunking of the reversed data
+        let reversed: Vec<u8> = data.iter().rev().copied().collect();
+        let mirrored: Vec<usize> = chunker.chunk_all(&reversed).map(|c| c.len).collect();
+        let lens: Vec<usize> = chunks.iter().rev().map(|c| c.len).collect();
+        assert_eq!(lens, mirrored);
+
+        // Prepending data keeps the tail chunks
+        let mut grown = TestDataGenerator::generate_pseudo_random(30_000, 26);
+        grown.extend_from_slice(&data);
+        let grown_chunks = chunker.chunk_all_reverse(&grown);
+        let tail = chunks.len() - 1;
+        for (old, new) in chunks.iter().rev().zip(grown_chunks.iter().rev()).take(tail) {
+            assert_eq!(old.data, new.data);
+            assert_eq!(old.start + 30_000, new.start);
+        }
+
+        assert!(chunker.chunk_all_reverse(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_chunker_trait_objects() {
+        use crate::utils::TestDataGenerator;
+
+        fn boundaries(chunker: &dyn Chunker, data: &[u8]) -> Vec<usize> {
+            chunker.chunk_all(data).map(|chunk| chunk.end()).collect()
+        }
+
+        let data = TestDataGenerator::generate_pseudo_random(100_000, 27);
+        let chunkers: Vec<Box<dyn Chunker>> = vec![
+            Box::new(SeqChunking::new()),
+            Box::new(SeqChunking::from_config(
+                ChunkingConfig::builder().cut_strategy(CutStrategy::Gear { mask: 0x1fff }).build().unwrap(),
+            )),
+        ];
+
+        for chunker in &chunkers {
+            let ends = boundaries(chunker.as_ref(), &data);
+            assert_eq!(*ends.last().unwrap(), data.len());
+            assert_eq!(chunker.find_cutpoint(&data, data.len() as u64) as usize, ends[0]);
+        }
+        assert_eq!(boundaries(chunkers[0].as_ref(), &data), SeqChunking::new().chunk_offsets(&data));
+        assert_ne!(boundaries(chunkers[0].as_ref(), &data), boundaries(chunkers[1].as_ref(), &data));
+    }
+
+    #[test]
+    fn test_chunk_all_instrumented() {
+        use crate::utils::TestDataGenerator;
+
+        let chunker = SeqChunking::new();
+        let data = TestDataGenerator::generate_pseudo_random(200_000, 17);
+        let (chunks, metrics) = chunker.chunk_all_instrumented(&data);
+        let plain: Vec<_> = chunker.chunk_all(&data).collect();
+        assert_eq!(chunks, plain);
+        assert!(metrics.comparisons > 0);
+        assert!(metrics.jumps > 0);
+
+        let flat = vec![9u8; 100_000];
+        let (chunks, metrics) = chunker.chunk_all_instrumented(&flat);
+        assert_eq!(chunks, chunker.chunk_all(&flat).collect::<Vec<_>>());
+        assert_eq!(metrics.forced_max_cuts, chunks.len() as u64 - 1, "the short tail is not a forced cut");
+        assert_eq!(metrics.absorbed_bytes, metrics.comparisons);
+        assert_eq!(metrics.jumps, 0);
+    }
+
+    #[test]
+    fn test_huge_jump_size() {
+        use crate::utils::{TestDataGenerator, ValidationUtils};
+
+        // Bypasses validation, which rejects such a jump
+        let config = ChunkingConfig { jump_trigger: 1, jump_size: u64::MAX, ..ChunkingConfig::default() };
+        let chunker = SeqChunking::from_config(config);
+
+        let data = TestDataGenerator::generate_pseudo_random(100_000, 29);
+        let chunks: Vec<_> = chunker.chunk_all(&data).collect();
+        ValidationUtils::validate_chunk_coverage(data.len(), &chunks).unwrap();
+        assert!(chunks.iter().all(|chunk| chunk.len <= DEFAULT_MAX_BLOCK_SIZE as usize));
+    }
+
+    #[test]
+    fn test_alternation_threshold() {
+        let data: Vec<u8> = (0..100_000).map(|i| (i % 2) as u8).collect();
+
+        // Alternating steps never form a run, so every cut is forced
+        let plain = SeqChunking::new();
+        let sizes: Vec<_> = plain.chunk_all(&data).map(|chunk| chunk.len).collect();
+        assert!(sizes[..sizes.len() - 1].iter().all(|&len| len == DEFAULT_MAX_BLOCK_SIZE as usize));
+
+        for op_mode in [SeqOpMode::Increasing, SeqOpMode::Decreasing] {
+            let config = ChunkingConfig::builder().op_mode(op_mode).alternation_threshold(Some(64)).build().unwrap();
+            let chunker = SeqChunking::from_config(config);
+            let chunks: Vec<_> = chunker.chunk_all(&data).collect();
+            assert!(chunks.len() > sizes.len() * 3);
+
+            // Every cut lands on a step along op_mode
+            let step_end = if op_mode == SeqOpMode::Increasing { 1 } else { 0 };
+            assert!(chunks[1..].iter().all(|chunk| chunk.data[0] == step_end));
+            assert!(chunks.iter().all(|chunk| chunk.len < DEFAULT_MAX_BLOCK_SIZE as usize));
+        }
+
+        let enabled = ChunkingConfig::builder().alternation_threshold(Some(64)).build().unwrap();
+        assert_ne!(SeqChunking::from_config(enabled).algorithm_fingerprint(), plain.algorithm_fingerprint());
+        assert!(ChunkingConfig::builder().alternation_threshold(Some(0)).build().is_err());
+    }
+
+    #[test]
+    fn test_chunk_all_rev_iter() {
+        use crate::utils::TestDataGenerator;
+
+        let data = TestDataGenerator::generate_mixed_patterns(200_000);
+        let chunker = SeqChunking::new();
+
+        let mut reversed: Vec<_> = chunker.chunk_all_rev_iter(&data).collect();
+        assert_eq!(reversed.last().unwrap().start, 0);
+        assert_eq!(reversed[0].end(), data.len());
+        reversed.reverse();
+        assert_eq!(reversed, chunker.chunk_all(&data).collect::<Vec<_>>());
+
+        assert_eq!(chunker.chunk_all_rev_iter(&[]).count(), 0);
+    }
+
+    #[test]
+    fn test_stats_streaming() {
+        use crate::utils::TestDataGenerator;
+
+        let data = TestDataGenerator::generate_mixed_patterns(500_000);
         let chunker = SeqChunking::new();
-        assert_eq!(chunker.technique_name(), "Seq Chunking");
-        assert_eq!(chunker.config().seq_threshold, 5);
+        let (streamed, collected) = (chunker.stats_streaming(&data), chunker.stats(&data));
+
+        assert_eq!(streamed.chunk_count, collected.chunk_count);
+        assert_eq!(streamed.total_size, collected.total_size);
+        assert_eq!(streamed.min_chunk_size, collected.min_chunk_size);
+        assert_eq!(streamed.max_chunk_size, collected.max_chunk_size);
+        assert!((streamed.avg_chunk_size - collected.avg_chunk_size).abs() < 1e-6);
+        assert!((streamed.chunk_size_stddev - collected.chunk_size_stddev).abs() < 1e-6);
+        assert_eq!(streamed.median_chunk_size, 0);
+
+        let empty = chunker.stats_streaming(&[]);
+        assert_eq!((empty.chunk_count, empty.min_chunk_size, empty.avg_chunk_size), (0, 0, 0.0));
     }
 
     #[test]
-    fn test_seq_chunking_from_config() {
-        let config = ChunkingConfig::builder().seq_threshold(10).build().unwrap();
+    fn test_chunk_iterator_size_hint() {
+        use crate::utils::TestDataGenerator;
+
+        let data = TestDataGenerator::generate_mixed_patterns(300_000);
+        let configs = [
+            ChunkingConfig::default(),
+            ChunkingConfig { fast_first_chunk: true, ..ChunkingConfig::default() },
+            ChunkingConfig { fallback: FallbackPolicy::FullRemaining, ..ChunkingConfig::default() },
+        ];
+
+        for config in configs {
+            let chunker = SeqChunking::from_config(config);
+            let mut count = chunker.chunk_all(&data).count();
+            let mut iter = chunker.chunk_all(&data);
+            loop {
+                let (lower, upper) = iter.size_hint();
+                assert!(lower <= count && count <= upper.unwrap(), "{} outside {}..={:?}", count, lower, upper);
+                if iter.next().is_none() {
+                    break;
+                }
+                count -= 1;
+            }
+            assert_eq!(count, 0);
+        }
+    }
+
+    #[test]
+    fn test_window_step() {
+        use crate::utils::ValidationUtils;
+
+        // A rising ramp on even positions interleaved with a falling one on odd positions
+        let data: Vec<u8> = (0..100_000)
+            .map(|i| if i % 2 == 0 { (i / 2 % 256) as u8 } else { 255 - (i / 2 % 256) as u8 })
+            .collect();
+        let chunker_for = |window_step| {
+            SeqChunking::from_config(ChunkingConfig::builder().slope_window(2).window_step(window_step).build().unwrap())
+        };
+
+        // Overlapping comparisons alternate between both ramps and never form a run
+        let (overlapping_chunker, stepped_chunker) = (chunker_for(false), chunker_for(true));
+        let overlapping: Vec<_> = overlapping_chunker.chunk_all(&data).collect();
+        assert!(overlapping[..overlapping.len() - 1].iter().all(|chunk| chunk.len == DEFAULT_MAX_BLOCK_SIZE as usize));
+
+        // Stepped comparisons follow the rising ramp alone
+        let stepped: Vec<_> = stepped_chunker.chunk_all(&data).collect();
+        assert!(stepped.iter().all(|chunk| chunk.len < DEFAULT_AVG_BLOCK_SIZE as usize));
+
+        for chunks in [&overlapping, &stepped] {
+            ValidationUtils::validate_chunk_coverage(data.len(), chunks).unwrap();
+            assert_eq!(chunks.iter().flat_map(|chunk| chunk.data.iter().copied()).collect::<Vec<_>>(), data);
+        }
+        assert_ne!(stepped_chunker.algorithm_fingerprint(), overlapping_chunker.algorithm_fingerprint());
+    }
+
+    #[test]
+    fn test_chunk_segments() {
+        use crate::utils::TestDataGenerator;
+
+        let mut data = TestDataGenerator::generate_pseudo_random(150_000, 30);
+        data.extend(TestDataGenerator::generate_mixed_patterns(100_000));
+        let cuts = [0, 0, 1, 2, 5000, 5000, 40_000, 123_457, 123_458, 200_000, data.len()];
+        let segments: Vec<&[u8]> = cuts.windows(2).map(|pair| &data[pair[0]..pair[1]]).collect();
+
+        let configs = [
+            ChunkingConfig::default(),
+            ChunkingConfig { fallback: FallbackPolicy::FullRemaining, ..ChunkingConfig::default() },
+            ChunkingConfig { max_size_ratio: Some(1.5), ..ChunkingConfig::default() },
+        ];
+        for config in configs {
+            let chunker = SeqChunking::from_config(config);
+            let expected: Vec<_> = chunker.chunk_all_owned(&data);
+            assert_eq!(chunker.chunk_segments(&segments).collect::<Vec<_>>(), expected);
+            assert_eq!(chunker.chunk_segments(&[&data]).collect::<Vec<_>>(), expected);
+        }
+
+        let chunker = SeqChunking::new();
+        assert_eq!(chunker.chunk_segments(&[]).count(), 0);
+        assert_eq!(chunker.chunk_segments(&[&[], &[]]).count(), 0);
+    }
+
+    #[test]
+    fn test_resume_from() {
+        use crate::utils::TestDataGenerator;
+
+        let data = TestDataGenerator::generate_mixed_patterns(300_000);
+        let chunker = SeqChunking::from_config(ChunkingConfig { fast_first_chunk: true, ..ChunkingConfig::default() });
+        let chunks: Vec<_> = chunker.chunk_all(&data).collect();
+
+        for checkpoint in [0, 1, chunks.len() / 2, chunks.len() - 1] {
+            let resumed: Vec<_> = chunker.resume_from(&data, chunks[checkpoint].start).collect();
+            assert_eq!(resumed, chunks[checkpoint..]);
+        }
+        assert_eq!(chunker.resume_from(&data, data.len()).count(), 0);
+        assert_eq!(chunker.resume_from(&data, data.len() + 1).count(), 0);
+    }
+
+    #[test]
+    fn test_warmup_bytes() {
+        use crate::utils::{TestDataGenerator, ValidationUtils};
+
+        let chunker_for = |warmup| {
+            let config = ChunkingConfig::builder()
+                .min_block_size(64)
+                .avg_block_size(128)
+                .max_block_size(256)
+                .warmup_bytes(warmup)
+                .build()
+                .unwrap();
+            SeqChunking::from_config(config)
+        };
+
+        // A rise of six slopes straddling the minimum size, in data that otherwise alternates
+        let mut data: Vec<u8> = (0..256).map(|i| (i % 2) as u8).collect();
+        for (i, byte) in data[60..67].iter_mut().enumerate() {
+            *byte = 10 * (i as u8 + 1);
+        }
+        assert_eq!(chunker_for(0).find_cutpoint(&data, 256), 256);
+        assert_eq!(chunker_for(8).find_cutpoint(&data, 256), 64);
+        // Cuts never move before the minimum size
+        assert_eq!(chunker_for(63).find_cutpoint(&data, 256), 64);
+
+        let data = TestDataGenerator::generate_pseudo_random(100_000, 31);
+        let (plain, warm) = (chunker_for(0), chunker_for(16));
+        let chunks: Vec<_> = warm.chunk_all(&data).collect();
+        ValidationUtils::validate_chunk_coverage(data.len(), &chunks).unwrap();
+        assert!(chunks[..chunks.len() - 1].iter().all(|chunk| chunk.len >= 64));
+        assert_ne!(warm.chunk_offsets(&data), plain.chunk_offsets(&data));
+        assert_ne!(warm.algorithm_fingerprint(), plain.algorithm_fingerprint());
+    }
+
+    #[test]
+    fn test_uniform_run_shortcut() {
+        use crate::utils::TestDataGenerator;
+
+        let mut data = vec![0u8; 70_000];
+        data.extend(TestDataGenerator::generate_pseudo_random(50_000, 32));
+        data.extend([0xaa; 40_000]);
+        data.extend(TestDataGenerator::generate_pseudo_random(20_000, 33));
+        data.extend([0u8; 16_384]);
+
+        let configs = [
+            ChunkingConfig::default(),
+            ChunkingConfig { fallback: FallbackPolicy::AvgBlock, ..ChunkingConfig::default() },
+            ChunkingConfig { fallback: FallbackPolicy::FullRemaining, ..ChunkingConfig::default() },
+            ChunkingConfig { max_size_ratio: Some(1.5), fast_first_chunk: true, ..ChunkingConfig::default() },
+            ChunkingConfig { max_absorption_ratio: Some(0.9), ..ChunkingConfig::default() },
+            ChunkingConfig {
+                op_mode: SeqOpMode::Decreasing,
+                seq_threshold: 1,
+                leading_sentinel: Some(255),
+                ..ChunkingConfig::default()
+            },
+        ];
+        for config in configs {
+            let chunker = SeqChunking::from_config(config);
+            // chunk_all_instrumented always scans, so it is the reference
+            let (scanned, _) = chunker.chunk_all_instrumented(&data);
+            assert_eq!(chunker.chunk_all(&data).collect::<Vec<_>>(), scanned);
+        }
+
+        let zeros = vec![0u8; 100_000];
+        let chunker = SeqChunking::new();
+        let max = DEFAULT_MAX_BLOCK_SIZE as usize;
+        let sizes: Vec<_> = chunker.chunk_all(&zeros).map(|chunk| chunk.len).collect();
+        assert_eq!(sizes, [vec![max; 6], vec![100_000 - 6 * max]].concat());
+    }
+
+    #[test]
+    fn test_chunk_iterator_matches_cutpoint_loop() {
+        use crate::utils::TestDataGenerator;
+
+        let mut data = TestDataGenerator::generate_pseudo_random(100_000, 34);
+        data.extend([0u8; 40_000]);
+        data.extend(TestDataGenerator::generate_mixed_patterns(60_000));
+
+        for seed in 0..50 {
+            let chunker = SeqChunking::from_config(ChunkingConfig::random_valid(seed));
+
+            // One cutpoint search per chunk over the re-sliced remainder
+            let mut expected = Vec::new();
+            let (mut position, mut prev_len) = (0, None);
+            while position < data.len() {
+                let remaining = &data[position..];
+                let cut = chunker.find_cutpoint_at(remaining, remaining.len() as u64, position) as usize;
+                let len = chunker.smooth_len(cut, prev_len, remaining.len()).min(remaining.len());
+                position += len;
+                prev_len = Some(len);
+                expected.push(position);
+            }
+
+            assert_eq!(chunker.chunk_offsets(&data), expected, "seed {}", seed);
+        }
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn test_chunk_bytes() {
+        use crate::utils::TestDataGenerator;
+
+        let data = Bytes::from(TestDataGenerator::generate_pseudo_random(200_000, 38));
+        let chunker = SeqChunking::new();
+        let chunks: Vec<BytesChunk> = chunker.chunk_bytes(data.clone()).collect();
+
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().map(BytesChunk::as_chunk).eq(chunker.chunk_all(&data)));
+        for chunk in &chunks {
+            // Slices of the same allocation, not copies
+            assert_eq!(chunk.data.as_ptr(), data[chunk.range()].as_ptr());
+        }
+        assert_eq!(chunker.chunk_bytes(Bytes::new()).count(), 0);
+    }
+
+    #[test]
+    fn test_chunk_all_refined() {
+        use crate::utils::{reconstruct, TestDataGenerator};
+
+        let mut data = TestDataGenerator::generate_pseudo_random(200_000, 35);
+        data.extend(TestDataGenerator::generate_mixed_patterns(100_000));
+
+        for op_mode in [SeqOpMode::Increasing, SeqOpMode::Decreasing] {
+            let chunker = SeqChunking::from_config(ChunkingConfig { op_mode, ..ChunkingConfig::default() });
+            assert_eq!(chunker.chunk_all_refined(&data, 0), chunker.chunk_all_owned(&data));
+
+            let refined = chunker.chunk_all_refined(&data, 64);
+            assert_eq!(reconstruct(&refined), data);
+            assert!(refined.windows(2).all(|pair| pair[0].start + pair[0].len == pair[1].start));
+            let (min, max) = (DEFAULT_MIN_BLOCK_SIZE as usize, DEFAULT_MAX_BLOCK_SIZE as usize);
+            assert!(refined[..refined.len() - 1].iter().all(|chunk| (min..=max).contains(&chunk.len)));
+            assert!(refined.last().unwrap().len <= max);
+            assert_ne!(refined, chunker.chunk_all_owned(&data));
+        }
+
+        assert!(SeqChunking::new().chunk_all_refined(&[], 64).is_empty());
+
+        // Boundaries moved back must not leave a tail longer than max_block_size
+        let config = ChunkingConfig::builder()
+            .min_block_size(64)
+            .avg_block_size(128)
+            .max_block_size(256)
+            .seq_threshold(3)
+            .build()
+            .unwrap();
         let chunker = SeqChunking::from_config(config);
-        assert_eq!(chunker.config().seq_threshold, 10);
+        for seed in [521, 522, 876, 1714] {
+            let data = TestDataGenerator::generate_pseudo_random(1000, seed);
+            let refined = chunker.chunk_all_refined(&data, 32);
+            assert_eq!(reconstruct(&refined), data);
+            assert!(refined[..refined.len() - 1].iter().all(|chunk| (64..=256).contains(&chunk.len)), "seed {}", seed);
+            assert!(refined.last().unwrap().len <= 256, "seed {}", seed);
+        }
     }
 
     #[test]
-    fn test_find_cutpoint_small_size() {
+    fn test_reconstruction_property() {
+        use crate::utils::TestDataGenerator;
+
+        // Seeded random cases: arbitrary inputs of 0..100_000 bytes chunked
+        // with random valid configurations must always be covered exactly
+        let mut state: u64 = 0x5eedcdc;
+        let mut next = |bound: u64| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) % bound
+        };
+
+        for case in 0..300 {
+            // `random_valid` leaves these knobs at their defaults
+            let mut config = ChunkingConfig::random_valid(case);
+            let (min, max) = (config.min_block_size, config.max_block_size);
+            config.window_step = next(4) == 0;
+            config.wrapping_diff = next(4) == 0;
+            config.absorb_equal_bytes = next(4) != 0;
+            config.warmup_bytes = next(min + 1);
+            config.max_size_ratio = (next(3) == 0).then(|| 1.0 + next(40) as f64 / 10.0);
+            // Higher gates rescan past nearly every cut of the low-entropy inputs
+            config.min_chunk_entropy = (next(4) == 0).then(|| next(40) as f64 / 10.0);
+            config.alternation_threshold = (next(4) == 0).then(|| 1 + next(64));
+            config.max_scan_distance = (next(4) == 0).then(|| 1 + next(2 * max));
+            config.suppress_window = if next(4) == 0 { next(2 * max) } else { 0 };
+            if next(6) == 0 {
+                config.cut_strategy = CutStrategy::Gear { mask: (1 << next(16)) - 1 };
+            }
+            config.validate().unwrap();
+
+            let size = next(100_000) as usize;
+            let data = match next(4) {
+                0 => TestDataGenerator::generate_pseudo_random(size, case),
+                1 => TestDataGenerator::generate_mixed_patterns(size),
+                2 => (0..size).map(|i| (i / (1 + case as usize % 300)) as u8).collect(),
+                _ => (0..size).map(|i| (i % (2 + case as usize % 200)) as u8).collect(),
+            };
+
+            let chunker = SeqChunking::from_config(config.clone());
+            let chunks: Vec<Chunk> = chunker.chunk_all(&data).collect();
+            let context = format!("case {} with {} bytes, {:?}", case, size, config);
+            assert!(ValidationUtils::verify_chunks(&data, &chunks).unwrap(), "{}", context);
+            assert!(ValidationUtils::validate_chunk_coverage(data.len(), &chunks).is_ok(), "{}", context);
+            assert!(chunks.iter().all(|chunk| !chunk.is_empty()), "{}", context);
+            // Only a `FullRemaining` final chunk may exceed `max_block_size`
+            let full_remaining = config.fallback == FallbackPolicy::FullRemaining;
+            let last = chunks.len().saturating_sub(1);
+            for (i, chunk) in chunks.iter().enumerate() {
+                assert!(chunk.len as u64 <= max || (full_remaining && i == last), "chunk {} of {}", i, context);
+            }
+        }
+    }
+
+    #[test]
+    fn test_chunk_into_n() {
+        use crate::utils::{reconstruct, TestDataGenerator};
+
+        let random = TestDataGenerator::generate_pseudo_random(1 << 20, 39);
+        let mixed = TestDataGenerator::generate_mixed_patterns(1 << 20);
         let chunker = SeqChunking::new();
-        let data = vec![1, 2, 3, 4, 5];
-        let result = chunker.find_cutpoint(&data, 512);
-        assert_eq!(result, 512);
+        for data in [&random, &mixed] {
+            for n in [4, 16, 64, 256, 1024] {
+                let chunks = chunker.chunk_into_n(data, n);
+                assert_eq!(&reconstruct(&chunks), data);
+                assert!(chunks.len().abs_diff(n) * 5 <= n, "{} chunks for n = {}", chunks.len(), n);
+            }
+        }
+
+        assert!(chunker.chunk_into_n(&[], 8).is_empty());
+        assert_eq!(chunker.chunk_into_n(&random, 0).len(), 1);
     }
 
     #[test]
-    fn test_find_cutpoint_increasing() {
+    fn test_empty_input() {
         let chunker = SeqChunking::new();
-        let mut data = vec![0u8; 8192];
 
-        for i in 4096..4110 {
-            data[i] = (i - 4096) as u8;
+        assert_eq!(chunker.chunk_all(&[]).count(), 0);
+        assert!(chunker.chunk_first(&[]).is_none());
+        assert!(ValidationUtils::validate_chunk_coverage(0, &[]).is_ok());
+
+        let stats = chunker.stats(&[]);
+        assert!(stats.is_empty());
+        assert_eq!((stats.total_size, stats.avg_chunk_size), (0, 0.0));
+        assert!(chunker.stats_streaming(&[]).is_empty());
+
+        // A single byte is already a chunk
+        assert_eq!(chunker.chunk_first(&[1]).unwrap().len, 1);
+        assert!(!chunker.stats(&[1]).is_empty());
+    }
+
+    #[test]
+    fn test_suggest_seq_threshold() {
+        use crate::utils::TestDataGenerator;
+
+        let data = TestDataGenerator::generate_pseudo_random(1 << 20, 40);
+        for op_mode in [SeqOpMode::Increasing, SeqOpMode::Decreasing] {
+            let threshold = SeqChunking::suggest_seq_threshold(&data, op_mode);
+            assert!(threshold > 1, "threshold {}", threshold);
+
+            let config = ChunkingConfig::builder().op_mode(op_mode).seq_threshold(threshold).build().unwrap();
+            let count = SeqChunking::from_config(config).chunk_all(&data).count();
+            let (min, max) = (DEFAULT_MIN_BLOCK_SIZE as usize, DEFAULT_MAX_BLOCK_SIZE as usize);
+            assert!(count > data.len() / max && count < data.len() / min, "{} chunks", count);
         }
 
-        let result = chunker.find_cutpoint(&data, 8192);
-        assert!(result < 8192);
-        assert!(result > 4096);
+        // Runs of four rising steps (1, 2, 2, 3, 4, 9), the repeated byte absorbed
+        let ramps: Vec<u8> = (0..400).map(|i| [9, 1, 2, 2, 3, 4][i % 6]).collect();
+        assert_eq!(SeqChunking::suggest_seq_threshold(&ramps, SeqOpMode::Increasing), 4);
+        assert_eq!(SeqChunking::suggest_seq_threshold(&[], SeqOpMode::Increasing), DEFAULT_SEQ_THRESHOLD);
     }
 
     #[test]
-    fn test_chunk_iterator() {
+    fn test_max_scan_distance() {
+        use crate::StreamingChunker;
+        use crate::utils::TestDataGenerator;
+
+        let chunker_for = |distance| {
+            let config = ChunkingConfig::builder()
+                .min_block_size(4096)
+                .avg_block_size(8192)
+                .max_block_size(1 << 20)
+                .max_scan_distance(distance)
+                .build()
+                .unwrap();
+            SeqChunking::from_config(config)
+        };
+        let unbounded = chunker_for(None);
+        let bounded = chunker_for(Some(1024));
+
+        // Neither all-equal nor alternating data ever triggers a cut: without
+        // a bound every chunk scans up to max_block_size
+        let flat = vec![0u8; 4 << 20];
+        let alternating: Vec<u8> = (0..4 << 20).map(|i| (i % 2) as u8).collect();
+        for data in [&flat, &alternating] {
+            assert!(unbounded.chunk_all(data).all(|chunk| chunk.len == 1 << 20));
+
+            let (chunks, metrics) = bounded.chunk_all_instrumented(data);
+            assert!(chunks[..chunks.len() - 1].iter().all(|chunk| chunk.len == 4096 + 1024));
+            assert!(metrics.comparisons <= chunks.len() as u64 * 1024);
+            // The short tail is not scanned
+            assert_eq!(metrics.forced_max_cuts, chunks.len() as u64 - 1);
+        }
+
+        // Triggers within the distance are kept
+        let data = TestDataGenerator::generate_pseudo_random(1 << 20, 42);
+        let far = chunker_for(Some((1 << 20) - 4096));
+        assert!(far.chunk_all(&data).eq(unbounded.chunk_all(&data)));
+        assert_ne!(far.algorithm_fingerprint(), unbounded.algorithm_fingerprint());
+
+        let streamed: Vec<OwnedChunk> = {
+            let mut streaming = StreamingChunker::new(bounded.clone());
+            let mut chunks = streaming.push(&alternating);
+            chunks.extend(streaming.finish());
+            chunks
+        };
+        assert_eq!(streamed, bounded.chunk_all_owned(&alternating));
+        assert!(ChunkingConfig::builder().max_scan_distance(Some(0)).build().is_err());
+    }
+
+    #[test]
+    fn test_max_consecutive_jumps() {
+        // Alternating bytes produce an opposing slope every other byte
+        let data: Vec<u8> = (0..DEFAULT_MAX_BLOCK_SIZE as usize)
+            .map(|i| if i % 2 == 0 { 0 } else { 255 })
+            .collect();
+
+        let unbounded = SeqChunking::new();
+        assert_eq!(unbounded.find_cutpoint(&data, data.len() as u64), DEFAULT_MAX_BLOCK_SIZE);
+
+        let config = ChunkingConfig::builder()
+            .max_consecutive_jumps(Some(3))
+            .build()
+            .unwrap();
+        let capped = SeqChunking::from_config(config);
+        let cut = capped.find_cutpoint(&data, data.len() as u64);
+        assert!(cut > DEFAULT_MIN_BLOCK_SIZE && cut < DEFAULT_MAX_BLOCK_SIZE);
+        assert!(cut < DEFAULT_MIN_BLOCK_SIZE + 3 * (2 * DEFAULT_JUMP_TRIGGER + DEFAULT_JUMP_SIZE));
+    }
+
+    /// Canary for boundary drift across platforms and releases
+    ///
+    /// Hashes every chunk end offset (as little-endian u64) of a fixed 1MB
+    /// input with FNV-1a. If this constant changes, chunk boundaries changed
+    /// and previously stored chunks will no longer deduplicate.
+    #[test]
+    fn test_boundary_determinism_canary() {
+        use crate::utils::TestDataGenerator;
+
+        let data = TestDataGenerator::generate_pseudo_random(1 << 20, 0x05ee_dcdc);
         let chunker = SeqChunking::new();
-        let data = b"Hello, World! This is a test.";
 
-        let chunks: Vec<_> = chunker.chunk_all(data).collect();
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        let mut count = 0;
+        for chunk in chunker.chunk_all(&data) {
+            for byte in (chunk.end() as u64).to_le_bytes() {
+                hash ^= byte as u64;
+                hash = hash.wrapping_mul(0x0100_0000_01b3);
+            }
+            count += 1;
+        }
 
-        assert!(!chunks.is_empty());
+        assert_eq!(count, CANARY_CHUNK_COUNT);
+        assert_eq!(hash, CANARY_BOUNDARY_HASH);
+    }
 
-        // Verify reconstruction
-        let reconstructed: Vec<u8> = chunks
-            .iter()
-            .flat_map(|chunk| chunk.data.iter())
-            .copied()
+    #[test]
+    fn test_shard() {
+        use crate::utils::{TestDataGenerator, ValidationUtils};
+
+        let chunker = SeqChunking::new();
+        let data = TestDataGenerator::generate_pseudo_random(10_000_000, 17);
+
+        for k in [1, 4, 16, 100] {
+            let shards = chunker.shard(&data, k);
+            assert!(shards.len() >= k.div_ceil(2) && shards.len() <= k, "k={} got {}", k, shards.len());
+
+            let borrowed: Vec<_> = shards.iter().map(|s| s.as_chunk()).collect();
+            ValidationUtils::validate_chunk_coverage(data.len(), &borrowed).unwrap();
+        }
+
+        assert!(chunker.shard(&[], 4).is_empty());
+
+        // The chunker's own fallback must not swallow the rest of the data
+        let config = ChunkingConfig::builder()
+            .fallback(FallbackPolicy::FullRemaining)
+            .max_size_ratio(Some(2.0))
+            .build()
+            .unwrap();
+        let data = TestDataGenerator::generate_pseudo_random(100_000, 18);
+        let shards = SeqChunking::from_config(config).shard(&data, 200);
+        assert!(shards.len() >= 100 && shards.len() <= 200, "got {}", shards.len());
+    }
+
+    #[test]
+    fn test_slope_window() {
+        // Single-byte spikes every other byte break adjacent runs, but the
+        // underlying ramp rises when compared two bytes apart
+        let mut data = vec![0u8; 128];
+        for (i, byte) in data[64..96].iter_mut().enumerate() {
+            *byte = if i % 2 == 0 { 10 + i as u8 } else { 200 };
+        }
+        let chunker_for = |window| {
+            let config = ChunkingConfig::builder()
+                .min_block_size(64)
+                .max_block_size(128)
+                .slope_window(window)
+                .build()
+                .unwrap();
+            SeqChunking::from_config(config)
+        };
+
+        assert_eq!(chunker_for(1).find_cutpoint(&data, 128), 128);
+        let cut = chunker_for(2).find_cutpoint(&data, 128);
+        assert!(cut > 64 && cut < 96);
+
+        let invalid = ChunkingConfig::builder().slope_window(0).build();
+        assert!(invalid.is_err());
+        let invalid = ChunkingConfig::builder().min_block_size(8).slope_window(8).build();
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    fn test_chunk_offsets() {
+        use crate::utils::TestDataGenerator;
+
+        let chunker = SeqChunking::new();
+        let data = TestDataGenerator::generate_pseudo_random(300_000, 23);
+
+        let offsets = chunker.chunk_offsets(&data);
+        let ends: Vec<usize> = chunker.chunk_all(&data).map(|c| c.end()).collect();
+        assert_eq!(offsets, ends);
+        assert_eq!(offsets.last(), Some(&data.len()));
+        assert!(chunker.chunk_offsets(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_estimated_memory() {
+        let chunker = SeqChunking::new();
+        let chunk_size = std::mem::size_of::<Chunk<'static>>();
+
+        assert_eq!(chunker.estimated_memory(0), 0);
+        assert_eq!(chunker.estimate_chunk_count(1), 1);
+        assert_eq!(chunker.estimate_chunk_count(DEFAULT_AVG_BLOCK_SIZE as usize * 10), 10);
+        assert_eq!(
+            chunker.estimated_memory(DEFAULT_AVG_BLOCK_SIZE as usize * 10),
+            10 * chunk_size
+        );
+        assert!(chunker.estimated_memory(1 << 30) > chunker.estimated_memory(1 << 20));
+
+        let config = ChunkingConfig::builder()
+            .min_block_size(1024)
+            .avg_block_size(2048)
+            .max_block_size(4096)
+            .build()
+            .unwrap();
+        let small = SeqChunking::from_config(config);
+        assert_eq!(small.estimated_memory(1 << 20), 4 * chunker.estimated_memory(1 << 20));
+    }
+
+    #[test]
+    fn test_max_absorption_ratio() {
+        use crate::utils::TestDataGenerator;
+
+        // Random prefix followed by a long run of identical bytes
+        let mut data = TestDataGenerator::generate_pseudo_random(DEFAULT_MAX_BLOCK_SIZE as usize, 31);
+        let run_start = DEFAULT_MIN_BLOCK_SIZE + 1000;
+        data[run_start as usize..].fill(0xAA);
+
+        let plain = SeqChunking::new();
+        let plain_cut = plain.find_cutpoint(&data, data.len() as u64);
+
+        let config = ChunkingConfig::builder()
+            .max_absorption_ratio(Some(0.5))
+            .build()
+            .unwrap();
+        let guarded = SeqChunking::from_config(config);
+        let guarded_cut = guarded.find_cutpoint(&data, data.len() as u64);
+
+        assert_eq!(plain_cut, DEFAULT_MAX_BLOCK_SIZE);
+        assert!(guarded_cut < plain_cut);
+        assert!(guarded_cut > run_start);
+
+        let invalid = ChunkingConfig::builder().max_absorption_ratio(Some(1.5)).build();
+        assert!(invalid.is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_chunk_to_channel() {
+        use crate::utils::TestDataGenerator;
+
+        let data: Arc<[u8]> = TestDataGenerator::generate_pseudo_random(300_000, 12).into();
+        let chunker = SeqChunking::new();
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        let consumer = std::thread::spawn(move || rx.iter().collect::<Vec<OwnedChunk>>());
+        chunker.chunk_to_channel(Arc::clone(&data), tx);
+        let chunks = consumer.join().unwrap();
+
+        let expected: Vec<OwnedChunk> = chunker.chunk_all(&data).map(OwnedChunk::from).collect();
+        assert_eq!(chunks, expected);
+        let reconstructed: Vec<u8> = chunks.iter().flat_map(|c| c.data.iter().copied()).collect();
+        assert_eq!(&reconstructed[..], &data[..]);
+
+        // A dropped receiver stops chunking without panicking
+        let (tx, rx) = std::sync::mpsc::channel();
+        drop(rx);
+        chunker.chunk_to_channel(data, tx);
+    }
+
+    #[test]
+    fn test_gear_strategy() {
+        use crate::utils::TestDataGenerator;
+
+        let data = TestDataGenerator::generate_pseudo_random(1 << 20, 29);
+        let config = ChunkingConfig::builder()
+            .cut_strategy(CutStrategy::Gear { mask: (1 << 12) - 1 })
+            .build()
+            .unwrap();
+        let gear = SeqChunking::from_config(config);
+        gear.validate_self(&data).unwrap();
+
+        let offsets = gear.chunk_offsets(&data);
+        assert_eq!(offsets, gear.chunk_offsets(&data));
+        assert_ne!(offsets, SeqChunking::new().chunk_offsets(&data));
+
+        // Expected size is min_block_size plus about 2^12, well below the max
+        let avg = data.len() / offsets.len();
+        let expected = (DEFAULT_MIN_BLOCK_SIZE + 4096) as usize;
+        assert!(avg > expected * 3 / 4 && avg < expected * 5 / 4, "avg {}", avg);
+    }
+
+    #[test]
+    fn test_monotonic_boundaries_are_arithmetic() {
+        // On a strictly increasing ramp every chunk cuts after seq_threshold
+        // rising slopes past min_block_size, visiting only those positions
+        let data: Vec<u32> = (0..1_000_000).collect();
+        let chunker = SeqChunker::<u32>::new();
+        let chunk_len = (DEFAULT_MIN_BLOCK_SIZE + DEFAULT_SEQ_THRESHOLD - 1) as usize;
+
+        let expected: Vec<usize> = (1..=data.len() / chunk_len)
+            .map(|i| i * chunk_len)
+            .chain((!data.len().is_multiple_of(chunk_len)).then_some(data.len()))
             .collect();
+        assert_eq!(chunker.chunk_offsets(&data), expected);
 
-        assert_eq!(reconstructed, data);
+        // Against the mode, every slope opposes and the scan jumps to max_block_size
+        let config = ChunkingConfig::builder().op_mode(SeqOpMode::Decreasing).build().unwrap();
+        let chunker = SeqChunker::<u32>::from_config(config);
+        let max = DEFAULT_MAX_BLOCK_SIZE as usize;
+        assert!(chunker.chunk_offsets(&data).iter().all(|&end| end.is_multiple_of(max) || end == data.len()));
     }
 
     #[test]
-    fn test_chunking_stats() {
+    fn test_wrapping_diff() {
+        // A counter wrapping 0..=255 keeps rising only when read modulo 256
+        let data: Vec<u8> = (0..40_000).map(|i| i as u8).collect();
+        let chunker_for = |wrapping| {
+            let config = ChunkingConfig::builder()
+                .min_block_size(1024)
+                .max_block_size(4096)
+                .seq_threshold(300)
+                .jump_trigger(1000)
+                .wrapping_diff(wrapping)
+                .build()
+                .unwrap();
+            SeqChunking::from_config(config)
+        };
+
+        let plain = chunker_for(false);
+        assert_eq!(plain.chunk_first(&data).unwrap().len, 4096);
+
+        let wrapping = chunker_for(true);
+        assert_eq!(wrapping.chunk_first(&data).unwrap().len, 1024 + 299);
+        assert_ne!(plain.algorithm_fingerprint(), wrapping.algorithm_fingerprint());
+
+        // Wider elements wrap at their own width
+        assert_eq!(u16::wrap_diff(0 - 65535), 1);
+        assert_eq!(i8::wrap_diff(-128 - 127), 1);
+        assert_eq!(u8::wrap_diff(3 - 10), -7);
+    }
+
+    #[test]
+    fn test_absorb_equal_bytes() {
+        let chunker_for = |op_mode, absorb| {
+            let config = ChunkingConfig::builder().op_mode(op_mode).absorb_equal_bytes(absorb).build().unwrap();
+            SeqChunking::from_config(config)
+        };
+
+        // All-equal data never cuts on content either way: absorbed bytes
+        // become opposing slopes and jumps, with the same fallback cuts
+        let flat = vec![7u8; 100_000];
+        for op_mode in [SeqOpMode::Increasing, SeqOpMode::Decreasing] {
+            let (absorbing, skipped) = chunker_for(op_mode, true).chunk_all_instrumented(&flat);
+            let (opposing, counted) = chunker_for(op_mode, false).chunk_all_instrumented(&flat);
+            assert_eq!(absorbing.len(), opposing.len());
+            assert_eq!(absorbing.len(), flat.len().div_ceil(DEFAULT_MAX_BLOCK_SIZE as usize));
+            assert!(skipped.absorbed_bytes > 0 && skipped.jumps == 0);
+            assert!(counted.absorbed_bytes == 0 && counted.jumps > 0);
+        }
+
+        // A staircase rises only across its repeated steps: absorbing them
+        // lets runs reach the threshold, counting them breaks every run
+        let stairs: Vec<u8> = (0..100_000).map(|i| (i / 2 % 64) as u8).collect();
+        let absorbing = chunker_for(SeqOpMode::Increasing, true);
+        let opposing = chunker_for(SeqOpMode::Increasing, false);
+        assert!(absorbing.chunk_all(&stairs).count() > opposing.chunk_all(&stairs).count());
+        assert_eq!(opposing.chunk_all(&stairs).count(), stairs.len().div_ceil(DEFAULT_MAX_BLOCK_SIZE as usize));
+        assert_ne!(absorbing.algorithm_fingerprint(), opposing.algorithm_fingerprint());
+
+        let parsed = ChunkingConfig::parse_kv("absorb_equal_bytes=false").unwrap();
+        assert!(!parsed.absorb_equal_bytes());
+    }
+
+    #[test]
+    fn test_seq_threshold_relaxed() {
+        use crate::utils::TestDataGenerator;
+
+        let data = TestDataGenerator::generate_pseudo_random(4 << 20, 43);
+        let max_sized = |relaxed| {
+            let config = ChunkingConfig::builder()
+                .seq_threshold(6)
+                .seq_threshold_relaxed(relaxed)
+                .build()
+                .unwrap();
+            let chunker = SeqChunking::from_config(config);
+            chunker
+                .chunk_all(&data)
+                .filter(|c| c.len == DEFAULT_MAX_BLOCK_SIZE as usize)
+                .count()
+        };
+
+        let strict = max_sized(None);
+        let relaxed = max_sized(Some(4));
+        assert!(relaxed < strict, "relaxed {} strict {}", relaxed, strict);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_chunk_entropy() {
+        use crate::utils::TestDataGenerator;
+
+        assert_eq!(Chunk::new(&[], 0, 0).entropy(), 0.0);
+        assert_eq!(Chunk::new(&[7u8; 100], 0, 100).entropy(), 0.0);
+        assert_eq!(Chunk::new(&[0u8, 1, 0, 1], 0, 4).entropy(), 1.0);
+
+        let all_bytes: Vec<u8> = (0..=255).collect();
+        assert_eq!(Chunk::new(&all_bytes, 0, 256).entropy(), 8.0);
+
+        let mut data = TestDataGenerator::generate_pseudo_random(100_000, 47);
+        data.extend(vec![b'x'; 50_000]);
         let chunker = SeqChunking::new();
-        let data = vec![42u8; 10000];
+        let entropies: Vec<f64> = chunker.chunk_all_with_entropy(&data).map(|(_, e)| e).collect();
+        assert_eq!(entropies.len(), chunker.chunk_all(&data).count());
+        assert!(entropies[0] > 7.5);
+        assert_eq!(*entropies.last().unwrap(), 0.0);
+    }
 
-        let stats = chunker.stats(&data);
-        assert_eq!(stats.total_size, 10000);
-        assert!(stats.chunk_count > 0);
-        assert!(stats.avg_chunk_size > 0.0);
+    #[test]
+    fn test_per_chunk_report() {
+        use crate::utils::TestDataGenerator;
+
+        let chunker = SeqChunking::new();
+        let mut data = TestDataGenerator::generate_pseudo_random(200_000, 37);
+        data.extend(vec![0u8; 20_000]);
+
+        let report = chunker.per_chunk_report(&data);
+        let chunks: Vec<_> = chunker.chunk_all(&data).collect();
+        assert_eq!(report.len(), chunks.len());
+
+        let mut expected_start = 0;
+        for (i, (record, chunk)) in report.iter().zip(&chunks).enumerate() {
+            assert_eq!(record.index, i);
+            assert_eq!(record.start, expected_start);
+            assert_eq!(record.len, chunk.len);
+            expected_start += record.len;
+        }
+        assert_eq!(expected_start, data.len());
+
+        #[cfg(feature = "std")]
+        {
+            assert!(report[0].entropy > 7.5);
+            assert_eq!(report.last().unwrap().entropy, 0.0);
+        }
+        #[cfg(feature = "hashing")]
+        assert_ne!(report[0].weak_hash, report[1].weak_hash);
     }
 
     #[test]
-    fn test_decreasing_mode() {
+    fn test_anchored_boundaries() {
+        use crate::utils::TestDataGenerator;
+
+        let chunker = SeqChunking::new();
+        let data = TestDataGenerator::generate_pseudo_random(500_000, 19);
+        let stride = 6000;
+
+        let anchored = chunker.anchored_boundaries(&data, stride);
+        assert_eq!(anchored.last(), Some(&data.len()));
+
+        let mut previous = 0;
+        for &boundary in &anchored {
+            assert!(boundary > previous && boundary - previous <= stride);
+            previous = boundary;
+        }
+
+        // Every slope cut is still a boundary, and some were forced in between
+        let slope_cuts = chunker.chunk_offsets(&data);
+        assert!(slope_cuts.iter().all(|cut| anchored.binary_search(cut).is_ok()));
+        assert!(anchored.len() > slope_cuts.len());
+
+        assert!(chunker.anchored_boundaries(&[], stride).is_empty());
+    }
+
+    #[test]
+    fn test_anchored_boundaries_suppress_window() {
+        use crate::utils::TestDataGenerator;
+
+        let stride = 6000;
         let config = ChunkingConfig::builder()
-            .op_mode(SeqOpMode::Decreasing)
+            .suppress_window(stride as u64 / 2)
             .build()
             .unwrap();
         let chunker = SeqChunking::from_config(config);
+        let data = TestDataGenerator::generate_pseudo_random(500_000, 19);
+
+        let gaps = |chunker: &SeqChunking| {
+            let mut previous = 0;
+            chunker
+                .anchored_boundaries(&data, stride)
+                .into_iter()
+                .map(|boundary| boundary - core::mem::replace(&mut previous, boundary))
+                .collect::<Vec<_>>()
+        };
+
+        // Up to half the stride, both bounds hold
+        let window = stride / 2;
+        assert!(gaps(&chunker).iter().all(|&gap| gap >= window && gap <= stride));
+
+        // A larger window wins over the stride
+        let config = ChunkingConfig::builder().suppress_window(5000).build().unwrap();
+        let chunker = SeqChunking::from_config(config);
+        let gaps = gaps(&chunker);
+        assert!(gaps[..gaps.len() - 1].iter().all(|&gap| gap >= 4096));
+        assert!(gaps.iter().any(|&gap| gap > stride));
+    }
 
-        let mut data = vec![255u8; 8192];
-        for i in 4096..4110 {
-            data[i] = (255 - (i - 4096)) as u8;
+    #[test]
+    fn test_required_lookback() {
+        let lookback = |transform, window| {
+            let config = ChunkingConfig::builder()
+                .transform(transform)
+                .slope_window(window)
+                .build()
+                .unwrap();
+            SeqChunking::from_config(config).required_lookback()
+        };
+
+        assert_eq!(SeqChunking::new().required_lookback(), DEFAULT_SLOPE_WINDOW as usize);
+        assert_eq!(lookback(SlopeTransform::Raw, 4), 4);
+        assert_eq!(lookback(SlopeTransform::FirstDiff, 4), 8);
+        assert_eq!(lookback(SlopeTransform::SecondDiff, 3), 9);
+    }
+
+    #[test]
+    fn test_fallback_policy() {
+        let data = vec![0u8; 100_000];
+        let sizes = |fallback| {
+            let config = ChunkingConfig::builder().fallback(fallback).build().unwrap();
+            let chunker = SeqChunking::from_config(config);
+            chunker.validate_self(&data).unwrap();
+            chunker.chunk_all(&data).map(|c| c.len).collect::<Vec<_>>()
+        };
+
+        let max = DEFAULT_MAX_BLOCK_SIZE as usize;
+        let max_sizes = sizes(FallbackPolicy::MaxBlock);
+        assert!(max_sizes[..max_sizes.len() - 1].iter().all(|&len| len == max));
+        assert_eq!(*max_sizes.last().unwrap(), data.len() % max);
+
+        let avg = DEFAULT_AVG_BLOCK_SIZE as usize;
+        let avg_sizes = sizes(FallbackPolicy::AvgBlock);
+        assert!(avg_sizes[..avg_sizes.len() - 1].iter().all(|&len| len == avg));
+        assert_eq!(*avg_sizes.last().unwrap(), data.len() % avg);
+
+        assert_eq!(sizes(FallbackPolicy::FullRemaining), vec![data.len()]);
+
+        // Fallback cuts are forced whatever the policy places them
+        let config = ChunkingConfig::builder().fallback(FallbackPolicy::AvgBlock).build().unwrap();
+        let reasons: Vec<_> = SeqChunking::from_config(config)
+            .chunk_all_with_reasons(&data)
+            .map(|(_, reason)| reason)
+            .collect();
+        assert!(reasons[..reasons.len() - 1].iter().all(|&reason| reason == CutReason::MaxBlockSize));
+        assert_eq!(*reasons.last().unwrap(), CutReason::EndOfData);
+    }
+
+    #[test]
+    fn test_leading_sentinel() {
+        let min = DEFAULT_MIN_BLOCK_SIZE as usize;
+        let mut data = vec![0u8; 2 * min];
+        for (i, byte) in data[min..min + 6].iter_mut().enumerate() {
+            *byte = 10 + i as u8;
         }
 
-        let result = chunker.find_cutpoint(&data, 8192);
-        assert!(result < 8192);
-        assert!(result > 4096);
+        let first_cut = |sentinel| {
+            let config = ChunkingConfig::builder()
+                .leading_sentinel(sentinel)
+                .build()
+                .unwrap();
+            let chunker = SeqChunking::from_config(config);
+            chunker.chunk_first(&data).unwrap().len
+        };
+
+        // A low sentinel makes the first comparison rising, a high one falling
+        assert_eq!(first_cut(Some(0)), min + 4);
+        assert_eq!(first_cut(Some(255)), min + 5);
+        assert_eq!(first_cut(None), min + 4);
+
+        // Only the start of the stream is affected
+        let chunker = SeqChunking::from_config(
+            ChunkingConfig::builder().leading_sentinel(Some(255)).build().unwrap(),
+        );
+        assert_eq!(chunker.find_cutpoint(&data, data.len() as u64), (min + 4) as u64);
     }
 
     #[test]
-    fn test_chunk_properties() {
-        let data = b"test data";
-        let chunk = Chunk::new(data, 0, data.len());
+    fn test_owned_chunk_roundtrip() {
+        let data = b"owned chunk data";
+        let chunk = Chunk::new(&data[6..], 6, data.len() - 6);
+        let owned = chunk.to_owned_chunk();
+
+        assert_eq!(owned.start, 6);
+        assert_eq!(owned.len, chunk.len);
+        assert_eq!(owned.end(), data.len());
+        assert_eq!(owned.as_chunk(), chunk);
+    }
 
-        assert_eq!(chunk.start, 0);
-        assert_eq!(chunk.len, data.len());
-        assert_eq!(chunk.end(), data.len());
-        assert!(!chunk.is_empty());
-        assert_eq!(chunk.data, data);
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_compressed_size() {
+        use crate::utils::TestDataGenerator;
+
+        let repetitive = vec![7u8; 8192];
+        let random = TestDataGenerator::generate_pseudo_random(8192, 99);
+
+        let repetitive_size = Chunk::new(&repetitive, 0, repetitive.len()).compressed_size(3);
+        let random_size = Chunk::new(&random, 0, random.len()).compressed_size(3);
+        assert!(repetitive_size * 10 < random_size);
+
+        let chunker = SeqChunking::new();
+        let stats = chunker.stats_with_compression(&repetitive, 3);
+        assert!(stats.total_compressed_size.unwrap() < repetitive.len());
+        assert_eq!(chunker.stats(&repetitive).total_compressed_size, None);
+    }
+
+    #[cfg(feature = "hashing")]
+    #[test]
+    fn test_chunk_all_chained() {
+        use crate::hashing::hash_bytes;
+        use crate::utils::TestDataGenerator;
+
+        let chunker = SeqChunking::new();
+        let data = TestDataGenerator::generate_pseudo_random(100_000, 7);
+
+        let chained: Vec<_> = chunker.chunk_all_chained(&data).collect();
+        assert!(chained.len() > 1);
+        assert_eq!(chained[0].1, [0u8; 32]);
+
+        for pair in chained.windows(2) {
+            assert_eq!(pair[1].1, hash_bytes(&pair[0].0.data));
+        }
     }
 }
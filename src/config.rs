@@ -2,9 +2,15 @@
 
 use crate::error::{ChunkingError, Result};
 use crate::*;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::str::FromStr;
 
-
+/// Direction of the byte sequences that trigger a cut
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum SeqOpMode {
     /// Detect increasing byte sequences
     #[default]
@@ -13,8 +19,177 @@ pub enum SeqOpMode {
     Decreasing,
 }
 
+/// Domain in which slopes are detected
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SlopeTransform {
+    /// Compare adjacent bytes
+    #[default]
+    Raw,
+    /// Compare adjacent first differences (needs one extra warmup byte)
+    FirstDiff,
+    /// Compare adjacent second differences (needs two extra warmup bytes)
+    SecondDiff,
+}
+
+/// Where to cut when the scan finds no sequence trigger
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum FallbackPolicy {
+    /// Cut at `max_block_size`
+    #[default]
+    MaxBlock,
+    /// Cut at `avg_block_size`
+    AvgBlock,
+    /// Take all remaining data as the final chunk
+    ///
+    /// This is the only policy under which a chunk may exceed
+    /// `max_block_size`, and only the final chunk can.
+    FullRemaining,
+}
+
+/// How [`SeqChunker::try_chunk_all`](crate::SeqChunker::try_chunk_all) treats input shorter than `min_block_size`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SmallInputPolicy {
+    /// Emit the whole input as a single chunk
+    #[default]
+    SingleChunk,
+    /// Fail with [`ChunkingError::InvalidInput`]
+    Error,
+}
+
+/// Algorithm used to pick cutpoints
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CutStrategy {
+    /// SeqCDC slope detection
+    #[default]
+    Slope,
+    /// Gear rolling hash, cutting where `hash & mask == 0`
+    ///
+    /// Meant for head-to-head comparison with slope detection. The expected
+    /// distance between cuts past `min_block_size` is `2^mask.count_ones()`.
+    Gear { mask: u64 },
+}
+
+impl FromStr for SeqOpMode {
+    type Err = ChunkingError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "increasing" | "inc" => Ok(SeqOpMode::Increasing),
+            "decreasing" | "dec" => Ok(SeqOpMode::Decreasing),
+            other => Err(ChunkingError::invalid_config(format!("unknown op_mode '{}'", other))),
+        }
+    }
+}
+
+impl FromStr for SlopeTransform {
+    type Err = ChunkingError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "raw" => Ok(SlopeTransform::Raw),
+            "first_diff" | "firstdiff" => Ok(SlopeTransform::FirstDiff),
+            "second_diff" | "seconddiff" => Ok(SlopeTransform::SecondDiff),
+            other => Err(ChunkingError::invalid_config(format!("unknown transform '{}'", other))),
+        }
+    }
+}
+
+impl FromStr for FallbackPolicy {
+    type Err = ChunkingError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "max_block" | "max" => Ok(FallbackPolicy::MaxBlock),
+            "avg_block" | "avg" => Ok(FallbackPolicy::AvgBlock),
+            "full_remaining" | "full" => Ok(FallbackPolicy::FullRemaining),
+            other => Err(ChunkingError::invalid_config(format!("unknown fallback '{}'", other))),
+        }
+    }
+}
+
+impl FromStr for SmallInputPolicy {
+    type Err = ChunkingError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "single_chunk" | "single" => Ok(SmallInputPolicy::SingleChunk),
+            "error" => Ok(SmallInputPolicy::Error),
+            other => Err(ChunkingError::invalid_config(format!("unknown small_input_policy '{}'", other))),
+        }
+    }
+}
+
+impl FromStr for CutStrategy {
+    type Err = ChunkingError;
+
+    /// Parse `slope` or `gear:<mask>`, with the mask in decimal or `0x` hex
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim().to_ascii_lowercase();
+        if s == "slope" {
+            return Ok(CutStrategy::Slope);
+        }
+
+        let invalid = || ChunkingError::invalid_config(format!("unknown cut_strategy '{}'", s));
+        let mask = s.strip_prefix("gear:").ok_or_else(invalid)?;
+        let mask = match mask.strip_prefix("0x") {
+            Some(hex) => u64::from_str_radix(hex, 16),
+            None => mask.parse(),
+        }
+        .map_err(|_| invalid())?;
+        Ok(CutStrategy::Gear { mask })
+    }
+}
+
+/// Parse a size such as `4096`, `4K`, `16KiB` or `1M` (binary multiples)
+pub fn parse_size(s: &str) -> Result<u64> {
+    let s = s.trim();
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (digits, suffix) = s.split_at(split);
+
+    let value: u64 = digits
+        .parse()
+        .map_err(|_| ChunkingError::invalid_config(format!("invalid size '{}'", s)))?;
+
+    let multiplier: u64 = match suffix.trim().to_ascii_lowercase().as_str() {
+        "" | "b" => 1,
+        "k" | "kb" | "kib" => 1 << 10,
+        "m" | "mb" | "mib" => 1 << 20,
+        "g" | "gb" | "gib" => 1 << 30,
+        _ => return Err(ChunkingError::invalid_config(format!("invalid size '{}'", s))),
+    };
+
+    value
+        .checked_mul(multiplier)
+        .ok_or_else(|| ChunkingError::invalid_config(format!("size '{}' overflows u64", s)))
+}
+
+/// Parse the value given for `key` in [`ChunkingConfig::parse_kv`]
+fn parse_value<T: FromStr>(key: &str, value: &str) -> Result<T> {
+    value
+        .parse()
+        .map_err(|_| ChunkingError::invalid_config(format!("invalid value '{}' for {}", value, key)))
+}
+
+/// Parse an optional `parse_kv` value, where `none` leaves it unset
+fn optional<T>(value: &str, parse: impl FnOnce(&str) -> Result<T>) -> Result<Option<T>> {
+    match value {
+        "none" => Ok(None),
+        _ => parse(value).map(Some),
+    }
+}
+
 /// Configuration for the chunking algorithm
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct ChunkingConfig {
     /// Number of consecutive sequence bytes needed to trigger a cut
     pub seq_threshold: u64,
@@ -30,6 +205,76 @@ pub struct ChunkingConfig {
     pub avg_block_size: u64,
     /// Maximum chunk size in bytes
     pub max_block_size: u64,
+    /// Looser sequence threshold used from `avg_block_size` on (`None` = off)
+    pub seq_threshold_relaxed: Option<u64>,
+    /// Normalization level tightening the chunk size distribution (0 = off)
+    pub normalization_level: u8,
+    /// Use a reduced minimum size for the first chunk to lower startup latency
+    pub fast_first_chunk: bool,
+    /// Domain in which slopes are detected
+    pub transform: SlopeTransform,
+    /// Read element differences modulo the element width, so a counter wrapping from 255 to 0 rises
+    pub wrapping_diff: bool,
+    /// Skip equal neighbours instead of counting them as opposing slopes
+    ///
+    /// Disabling absorption makes runs of equal bytes break rising (or
+    /// falling) sequences and trigger jumps, which suits formats where a flat
+    /// region is a meaningful signal.
+    pub absorb_equal_bytes: bool,
+    /// Force a cut after this many jumps without a cut (`None` = unbounded)
+    pub max_consecutive_jumps: Option<u64>,
+    /// Distance between the two bytes compared for a slope
+    pub slope_window: u64,
+    /// Advance the scan by `slope_window` instead of one position
+    ///
+    /// By default consecutive comparisons overlap: position `i` is compared
+    /// with `i - slope_window`, then `i + 1` with `i + 1 - slope_window`, so
+    /// a window of `w` follows `w` interleaved sequences. Stepping compares
+    /// each element once, with the one `slope_window` before it, following a
+    /// single sequence sampled every `w` elements and scanning `w` times fewer
+    /// positions.
+    pub window_step: bool,
+    /// Bytes before the minimum size that are scanned without cutting
+    ///
+    /// A run already under way at `min_block_size` then counts from where it
+    /// started, instead of restarting there. Cuts still never fall before
+    /// `min_block_size`.
+    pub warmup_bytes: u64,
+    /// Force a cut once the share of absorbed equal-byte positions exceeds this ratio
+    pub max_absorption_ratio: Option<f32>,
+    /// Predecessor assumed for the first comparison at the start of the stream
+    pub leading_sentinel: Option<u8>,
+    /// Where to cut when no sequence trigger is found
+    pub fallback: FallbackPolicy,
+    /// Algorithm used to pick cutpoints
+    pub cut_strategy: CutStrategy,
+    /// Minimum distance from a forced anchor cut to the next boundary
+    pub suppress_window: u64,
+    /// Skip cuts leaving a chunk with less entropy than this, in bits per byte (`None` = off)
+    ///
+    /// Elements wider than a byte contribute each of their little-endian bytes.
+    pub min_chunk_entropy: Option<f64>,
+    /// How `try_chunk_all` treats input shorter than `min_block_size`
+    pub small_input_policy: SmallInputPolicy,
+    /// Largest allowed ratio between consecutive chunk sizes (`None` = off)
+    ///
+    /// Cuts are moved after detection to smooth size transitions, trading
+    /// content-defined boundaries for more even chunk sizes: an insertion can
+    /// then shift the chunks that follow it.
+    pub max_size_ratio: Option<f64>,
+    /// Cut once this many slopes in a row alternate direction (`None` = off)
+    ///
+    /// Data such as `[0, 1, 0, 1, ...]` never forms a run in either
+    /// direction, so without this it is cut at `max_block_size`. The cut is
+    /// placed on the next step along `op_mode`, keeping it on a period
+    /// boundary of the pattern.
+    pub alternation_threshold: Option<u64>,
+    /// Stop looking for a trigger this far past `min_block_size` (`None` = up to `max_block_size`)
+    ///
+    /// A scan that finds no trigger within the distance cuts where it stopped,
+    /// so the time spent per chunk is bounded independently of how large
+    /// `max_block_size` lets chunks grow.
+    pub max_scan_distance: Option<u64>,
 }
 
 impl ChunkingConfig {
@@ -43,6 +288,31 @@ impl ChunkingConfig {
         ChunkingConfigBuilder::new()
     }
 
+    /// Preset for backup and deduplication: 2 KiB / 8 KiB / 64 KiB blocks
+    pub fn preset_backup() -> Self {
+        Self::builder()
+            .min_block_size(2 * 1024)
+            .avg_block_size(8 * 1024)
+            .max_block_size(64 * 1024)
+            .build_unchecked()
+    }
+
+    /// Preset for collections of small files: 512 B / 2 KiB / 8 KiB blocks
+    pub fn preset_small_files() -> Self {
+        Self::builder()
+            .min_block_size(512)
+            .avg_block_size(2 * 1024)
+            .max_block_size(8 * 1024)
+            .build_unchecked()
+    }
+
+    /// Preset for large media files: 64 KiB / 256 KiB / 1 MiB blocks
+    pub fn preset_large_media() -> Self {
+        Self::builder()
+            .target_avg(256 * 1024)
+            .build_unchecked()
+    }
+
     /// Validate the configuration parameters
     pub fn validate(&self) -> Result<()> {
         if self.seq_threshold == 0 {
@@ -56,14 +326,230 @@ impl ChunkingConfig {
         if self.max_block_size < self.min_block_size {
             return Err(ChunkingError::InvalidConfig("max_block_size must be >= min_block_size".into()));
         }
+
+        if self.avg_block_size < self.min_block_size {
+            return Err(ChunkingError::InvalidConfig("avg_block_size must be >= min_block_size".into()));
+        }
+
+        if self.avg_block_size > self.max_block_size {
+            return Err(ChunkingError::InvalidConfig("avg_block_size must be <= max_block_size".into()));
+        }
         
         if self.jump_size == 0 {
             return Err(ChunkingError::InvalidConfig("jump_size must be greater than 0".into()));
         }
+
+        if self.jump_size > MAX_JUMP_SIZE {
+            return Err(ChunkingError::InvalidConfig(format!("jump_size must be <= {}", MAX_JUMP_SIZE)));
+        }
+
+        if self.slope_window == 0 {
+            return Err(ChunkingError::InvalidConfig("slope_window must be greater than 0".into()));
+        }
+
+        if self.slope_window >= self.min_block_size {
+            return Err(ChunkingError::InvalidConfig("slope_window must be < min_block_size".into()));
+        }
+
+        if let Some(ratio) = self.max_absorption_ratio
+            && !(0.0..=1.0).contains(&ratio)
+        {
+            return Err(ChunkingError::InvalidConfig("max_absorption_ratio must be between 0.0 and 1.0".into()));
+        }
+
+        if let Some(entropy) = self.min_chunk_entropy
+            && !(0.0..=8.0).contains(&entropy)
+        {
+            return Err(ChunkingError::InvalidConfig("min_chunk_entropy must be between 0.0 and 8.0".into()));
+        }
+
+        if let Some(ratio) = self.max_size_ratio
+            && !(ratio >= 1.0 && ratio.is_finite())
+        {
+            return Err(ChunkingError::InvalidConfig("max_size_ratio must be a finite value >= 1.0".into()));
+        }
+
+        if self.seq_threshold_relaxed == Some(0) {
+            return Err(ChunkingError::InvalidConfig("seq_threshold_relaxed must be greater than 0".into()));
+        }
+
+        if self.max_consecutive_jumps == Some(0) {
+            return Err(ChunkingError::InvalidConfig("max_consecutive_jumps must be greater than 0".into()));
+        }
+
+        if self.alternation_threshold == Some(0) {
+            return Err(ChunkingError::InvalidConfig("alternation_threshold must be greater than 0".into()));
+        }
+
+        if self.max_scan_distance == Some(0) {
+            return Err(ChunkingError::InvalidConfig("max_scan_distance must be greater than 0".into()));
+        }
         
         Ok(())
     }
 
+    /// Advisory notes on settings that are valid but unlikely to work as intended
+    ///
+    /// On random data a slope runs in the `op_mode` direction about half the
+    /// time, so a run of `k` slopes takes about `2^(k+1) - 2` bytes to appear.
+    /// When the span between `min_block_size` and `max_block_size` is shorter
+    /// than that, nearly every chunk is cut by the fallback instead of by its
+    /// content.
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        let threshold = self.seq_threshold_relaxed.map_or(self.seq_threshold, |relaxed| relaxed.min(self.seq_threshold));
+        let expected_run_bytes = if threshold >= 62 { u64::MAX } else { (1u64 << (threshold + 1)) - 2 };
+        let span = self.max_block_size.saturating_sub(self.min_block_size);
+        if self.cut_strategy == CutStrategy::Slope && span < expected_run_bytes {
+            warnings.push(format!(
+                "max_block_size - min_block_size ({}) is shorter than the ~{} bytes a run of {} slopes takes on random data; most cuts will come from the fallback",
+                span, expected_run_bytes, threshold
+            ));
+        }
+
+        warnings
+    }
+
+    /// Parse a configuration from JSON and validate it
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self> {
+        let config: Self = serde_json::from_str(json)
+            .map_err(|e| ChunkingError::invalid_config(format!("Failed to parse JSON: {}", e)))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Parse a configuration from comma-separated `key=value` pairs
+    ///
+    /// Keys are the field names, with `min_block`, `avg_block`, `max_block`
+    /// and `mode` accepted as short forms. Unset keys keep their defaults and
+    /// the result is validated as by [`ChunkingConfigBuilder::build`].
+    ///
+    /// ```
+    /// use seq_chunking::{ChunkingConfig, SeqOpMode};
+    ///
+    /// let config = ChunkingConfig::parse_kv("seq_threshold=10,min_block=4K,op_mode=decreasing").unwrap();
+    /// assert_eq!(config.min_block_size(), 4096);
+    /// assert_eq!(config.op_mode(), SeqOpMode::Decreasing);
+    /// ```
+    pub fn parse_kv(s: &str) -> Result<Self> {
+        let mut builder = ChunkingConfigBuilder::new();
+
+        for pair in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| ChunkingError::invalid_config(format!("expected key=value, got '{}'", pair)))?;
+            let (key, value) = (key.trim(), value.trim());
+
+            let number = |value: &str| parse_value::<u64>(key, value);
+
+            builder = match key {
+                "seq_threshold" => builder.seq_threshold(number(value)?),
+                "jump_trigger" => builder.jump_trigger(number(value)?),
+                "jump_size" => builder.jump_size(parse_size(value)?),
+                "op_mode" | "mode" => builder.op_mode(value.parse()?),
+                "min_block" | "min_block_size" => builder.min_block_size(parse_size(value)?),
+                "avg_block" | "avg_block_size" => builder.avg_block_size(parse_size(value)?),
+                "max_block" | "max_block_size" => builder.max_block_size(parse_size(value)?),
+                "normalization_level" => builder.normalization_level(parse_value(key, value)?),
+                "fast_first_chunk" => builder.fast_first_chunk(parse_value(key, value)?),
+                "transform" => builder.transform(value.parse()?),
+                "window_step" => builder.window_step(parse_value(key, value)?),
+                "wrapping_diff" => builder.wrapping_diff(parse_value(key, value)?),
+                "absorb_equal_bytes" => builder.absorb_equal_bytes(parse_value(key, value)?),
+                "fallback" => builder.fallback(value.parse()?),
+                "small_input_policy" => builder.small_input_policy(value.parse()?),
+                "cut_strategy" => builder.cut_strategy(value.parse()?),
+                "suppress_window" => builder.suppress_window(parse_size(value)?),
+                "slope_window" => builder.slope_window(number(value)?),
+                "warmup_bytes" => builder.warmup_bytes(parse_size(value)?),
+                "leading_sentinel" => builder.leading_sentinel(optional(value, |v| parse_value(key, v))?),
+                "max_absorption_ratio" => builder.max_absorption_ratio(optional(value, |v| parse_value(key, v))?),
+                "min_chunk_entropy" => builder.min_chunk_entropy(optional(value, |v| parse_value(key, v))?),
+                "max_size_ratio" => builder.max_size_ratio(optional(value, |v| parse_value(key, v))?),
+                "alternation_threshold" => builder.alternation_threshold(optional(value, number)?),
+                "max_scan_distance" => builder.max_scan_distance(optional(value, number)?),
+                "seq_threshold_relaxed" => builder.seq_threshold_relaxed(optional(value, number)?),
+                "max_consecutive_jumps" => builder.max_consecutive_jumps(optional(value, number)?),
+                _ => return Err(ChunkingError::invalid_config(format!("unknown key '{}'", key))),
+            };
+        }
+
+        builder.build()
+    }
+
+    /// Generate a valid configuration deterministically from `seed`
+    ///
+    /// Meant for property tests and fuzzers. Parameters are drawn from:
+    ///
+    /// - `seq_threshold`: 1..=16, `seq_threshold_relaxed`: `None` or 1..=`seq_threshold`
+    /// - `jump_trigger`: 1..=200, `jump_size`: 1..=1024
+    /// - `min_block_size`: 64..=8192, `max_block_size`: 2..=8 times the minimum
+    /// - `avg_block_size`: anywhere between the minimum and the maximum
+    /// - `normalization_level`: 0..=3, `slope_window`: 1..=4
+    /// - `op_mode`, `transform`, `fallback`, `fast_first_chunk`: uniform
+    /// - `max_consecutive_jumps`: `None` or 1..=16
+    /// - `max_absorption_ratio`: `None` or 0.5..=1.0
+    /// - `leading_sentinel`: `None` or any byte
+    ///
+    /// The same seed always yields the same configuration.
+    pub fn random_valid(seed: u64) -> Self {
+        // SplitMix64
+        let mut state = seed;
+        let mut next = move || {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        };
+        let mut range = |lo: u64, hi: u64| lo + next() % (hi - lo + 1);
+
+        let min = range(64, 8192);
+        let max = min * range(2, 8);
+        let threshold = range(1, 16);
+
+        ChunkingConfigBuilder::new()
+            .seq_threshold(threshold)
+            .seq_threshold_relaxed(match range(0, threshold) {
+                0 => None,
+                relaxed => Some(relaxed),
+            })
+            .jump_trigger(range(1, 200))
+            .jump_size(range(1, 1024))
+            .op_mode(if range(0, 1) == 0 { SeqOpMode::Increasing } else { SeqOpMode::Decreasing })
+            .min_block_size(min)
+            .avg_block_size(range(min, max))
+            .max_block_size(max)
+            .normalization_level(range(0, 3) as u8)
+            .fast_first_chunk(range(0, 1) == 1)
+            .transform(match range(0, 2) {
+                0 => SlopeTransform::Raw,
+                1 => SlopeTransform::FirstDiff,
+                _ => SlopeTransform::SecondDiff,
+            })
+            .max_consecutive_jumps(match range(0, 16) {
+                0 => None,
+                cap => Some(cap),
+            })
+            .slope_window(range(1, 4))
+            .max_absorption_ratio(match range(0, 50) {
+                0 => None,
+                n => Some(0.5 + (n - 1) as f32 / 98.0),
+            })
+            .leading_sentinel(match range(0, 256) {
+                256 => None,
+                byte => Some(byte as u8),
+            })
+            .fallback(match range(0, 2) {
+                0 => FallbackPolicy::MaxBlock,
+                1 => FallbackPolicy::AvgBlock,
+                _ => FallbackPolicy::FullRemaining,
+            })
+            .build_unchecked()
+    }
+
     // Getters
     pub fn seq_threshold(&self) -> u64 { self.seq_threshold }
     pub fn jump_trigger(&self) -> u64 { self.jump_trigger }
@@ -72,26 +558,93 @@ impl ChunkingConfig {
     pub fn min_block_size(&self) -> u64 { self.min_block_size }
     pub fn avg_block_size(&self) -> u64 { self.avg_block_size }
     pub fn max_block_size(&self) -> u64 { self.max_block_size }
+    pub fn seq_threshold_relaxed(&self) -> Option<u64> { self.seq_threshold_relaxed }
+    pub fn normalization_level(&self) -> u8 { self.normalization_level }
+    pub fn fast_first_chunk(&self) -> bool { self.fast_first_chunk }
+    pub fn transform(&self) -> SlopeTransform { self.transform }
+    pub fn wrapping_diff(&self) -> bool { self.wrapping_diff }
+    pub fn absorb_equal_bytes(&self) -> bool { self.absorb_equal_bytes }
+    pub fn max_consecutive_jumps(&self) -> Option<u64> { self.max_consecutive_jumps }
+    pub fn slope_window(&self) -> u64 { self.slope_window }
+    pub fn window_step(&self) -> bool { self.window_step }
+    pub fn warmup_bytes(&self) -> u64 { self.warmup_bytes }
+    pub fn max_absorption_ratio(&self) -> Option<f32> { self.max_absorption_ratio }
+    pub fn leading_sentinel(&self) -> Option<u8> { self.leading_sentinel }
+    pub fn fallback(&self) -> FallbackPolicy { self.fallback }
+    pub fn cut_strategy(&self) -> CutStrategy { self.cut_strategy }
+    pub fn suppress_window(&self) -> u64 { self.suppress_window }
+    pub fn min_chunk_entropy(&self) -> Option<f64> { self.min_chunk_entropy }
+    pub fn small_input_policy(&self) -> SmallInputPolicy { self.small_input_policy }
+    pub fn max_size_ratio(&self) -> Option<f64> { self.max_size_ratio }
+    pub fn alternation_threshold(&self) -> Option<u64> { self.alternation_threshold }
+    pub fn max_scan_distance(&self) -> Option<u64> { self.max_scan_distance }
 }
 
-impl Default for ChunkingConfig {
-    fn default() -> Self {
+impl ChunkingConfig {
+    /// Create a configuration in a `const` context, e.g. for a `static` chunker
+    ///
+    /// Options not listed here take their default values. The configuration
+    /// is not validated; call [`validate`](Self::validate) before relying on it.
+    pub const fn const_new(
+        seq_threshold: u64,
+        jump_trigger: u64,
+        jump_size: u64,
+        op_mode: SeqOpMode,
+        min_block_size: u64,
+        avg_block_size: u64,
+        max_block_size: u64,
+    ) -> Self {
         Self {
-            seq_threshold: DEFAULT_SEQ_THRESHOLD,
-            jump_trigger: DEFAULT_JUMP_TRIGGER,
-            jump_size: DEFAULT_JUMP_SIZE,
-            op_mode: SeqOpMode::default(),
-            min_block_size: DEFAULT_MIN_BLOCK_SIZE,
-            avg_block_size: DEFAULT_AVG_BLOCK_SIZE,
-            max_block_size: DEFAULT_MAX_BLOCK_SIZE,
+            seq_threshold,
+            jump_trigger,
+            jump_size,
+            op_mode,
+            min_block_size,
+            avg_block_size,
+            max_block_size,
+            seq_threshold_relaxed: None,
+            normalization_level: 0,
+            fast_first_chunk: false,
+            transform: SlopeTransform::Raw,
+            wrapping_diff: false,
+            absorb_equal_bytes: true,
+            max_consecutive_jumps: None,
+            slope_window: DEFAULT_SLOPE_WINDOW,
+            window_step: false,
+            warmup_bytes: 0,
+            max_absorption_ratio: None,
+            leading_sentinel: None,
+            fallback: FallbackPolicy::MaxBlock,
+            cut_strategy: CutStrategy::Slope,
+            suppress_window: 0,
+            min_chunk_entropy: None,
+            small_input_policy: SmallInputPolicy::SingleChunk,
+            max_size_ratio: None,
+            alternation_threshold: None,
+            max_scan_distance: None,
         }
     }
 }
 
+impl Default for ChunkingConfig {
+    fn default() -> Self {
+        Self::const_new(
+            DEFAULT_SEQ_THRESHOLD,
+            DEFAULT_JUMP_TRIGGER,
+            DEFAULT_JUMP_SIZE,
+            SeqOpMode::Increasing,
+            DEFAULT_MIN_BLOCK_SIZE,
+            DEFAULT_AVG_BLOCK_SIZE,
+            DEFAULT_MAX_BLOCK_SIZE,
+        )
+    }
+}
+
 /// Builder pattern for ChunkingConfig
 #[derive(Debug)]
 pub struct ChunkingConfigBuilder {
     config: ChunkingConfig,
+    avg_explicit: bool,
 }
 
 impl ChunkingConfigBuilder {
@@ -99,9 +652,24 @@ impl ChunkingConfigBuilder {
     pub fn new() -> Self {
         Self {
             config: ChunkingConfig::default(),
+            avg_explicit: false,
         }
     }
 
+    /// Derive block sizes and threshold from a target average chunk size
+    ///
+    /// Sets `min_block_size` to `avg / 4`, `max_block_size` to `avg * 4`, and
+    /// raises `seq_threshold` by one for every factor of four above the default
+    /// average, since longer sequences are needed to space cuts further apart.
+    /// Later setters still override the derived values.
+    pub fn target_avg(mut self, avg: u64) -> Self {
+        let doublings = (avg / DEFAULT_AVG_BLOCK_SIZE).max(1).ilog2() as u64;
+        self.config.seq_threshold = DEFAULT_SEQ_THRESHOLD + doublings / 2;
+        self.config.min_block_size = avg / 4;
+        self.config.max_block_size = avg.saturating_mul(4);
+        self.avg_block_size(avg)
+    }
+
     /// Set the sequence threshold
     pub fn seq_threshold(mut self, threshold: u64) -> Self {
         self.config.seq_threshold = threshold;
@@ -135,6 +703,7 @@ impl ChunkingConfigBuilder {
     /// Set the average block size
     pub fn avg_block_size(mut self, size: u64) -> Self {
         self.config.avg_block_size = size;
+        self.avg_explicit = true;
         self
     }
 
@@ -144,14 +713,152 @@ impl ChunkingConfigBuilder {
         self
     }
 
+    /// Set the normalization level (0 disables normalization)
+    pub fn normalization_level(mut self, level: u8) -> Self {
+        self.config.normalization_level = level;
+        self
+    }
+
+    /// Enable or disable the reduced minimum size for the first chunk
+    pub fn fast_first_chunk(mut self, enabled: bool) -> Self {
+        self.config.fast_first_chunk = enabled;
+        self
+    }
+
+    /// Set the slope detection domain
+    pub fn transform(mut self, transform: SlopeTransform) -> Self {
+        self.config.transform = transform;
+        self
+    }
+
+    /// Set the maximum number of jumps before a cut is forced
+    pub fn max_consecutive_jumps(mut self, cap: Option<u64>) -> Self {
+        self.config.max_consecutive_jumps = cap;
+        self
+    }
+
+    /// Set the distance between the two bytes compared for a slope
+    pub fn slope_window(mut self, window: u64) -> Self {
+        self.config.slope_window = window;
+        self
+    }
+
+    /// Set whether the scan advances by `slope_window` instead of one position
+    pub fn window_step(mut self, enabled: bool) -> Self {
+        self.config.window_step = enabled;
+        self
+    }
+
+    /// Set the number of bytes before the minimum size that are scanned without cutting
+    pub fn warmup_bytes(mut self, bytes: u64) -> Self {
+        self.config.warmup_bytes = bytes;
+        self
+    }
+
+    /// Set the absorbed-byte ratio above which a cut is forced
+    pub fn max_absorption_ratio(mut self, ratio: Option<f32>) -> Self {
+        self.config.max_absorption_ratio = ratio;
+        self
+    }
+
+    /// Set the predecessor assumed for the first comparison of the stream
+    pub fn leading_sentinel(mut self, sentinel: Option<u8>) -> Self {
+        self.config.leading_sentinel = sentinel;
+        self
+    }
+
+    /// Set how `try_chunk_all` treats input shorter than `min_block_size`
+    pub fn small_input_policy(mut self, policy: SmallInputPolicy) -> Self {
+        self.config.small_input_policy = policy;
+        self
+    }
+
+    /// Set the largest allowed ratio between consecutive chunk sizes
+    pub fn max_size_ratio(mut self, ratio: Option<f64>) -> Self {
+        self.config.max_size_ratio = ratio;
+        self
+    }
+
+    /// Set the number of alternating slopes after which a cut is made
+    pub fn alternation_threshold(mut self, threshold: Option<u64>) -> Self {
+        self.config.alternation_threshold = threshold;
+        self
+    }
+
+    /// Set how far past the minimum block size to look for a trigger
+    pub fn max_scan_distance(mut self, distance: Option<u64>) -> Self {
+        self.config.max_scan_distance = distance;
+        self
+    }
+
+    /// Set where to cut when no sequence trigger is found
+    pub fn fallback(mut self, fallback: FallbackPolicy) -> Self {
+        self.config.fallback = fallback;
+        self
+    }
+
+    /// Set the algorithm used to pick cutpoints
+    pub fn cut_strategy(mut self, strategy: CutStrategy) -> Self {
+        self.config.cut_strategy = strategy;
+        self
+    }
+
+    /// Set a looser sequence threshold used from `avg_block_size` on
+    pub fn seq_threshold_relaxed(mut self, threshold: Option<u64>) -> Self {
+        self.config.seq_threshold_relaxed = threshold;
+        self
+    }
+
+    /// Set the minimum distance from a forced anchor cut to the next boundary
+    pub fn suppress_window(mut self, window: u64) -> Self {
+        self.config.suppress_window = window;
+        self
+    }
+
+    /// Read element differences modulo the element width
+    pub fn wrapping_diff(mut self, enabled: bool) -> Self {
+        self.config.wrapping_diff = enabled;
+        self
+    }
+
+    /// Skip equal neighbours (the default) or count them as opposing slopes
+    pub fn absorb_equal_bytes(mut self, enabled: bool) -> Self {
+        self.config.absorb_equal_bytes = enabled;
+        self
+    }
+
+    /// Set the minimum entropy, in bits per byte, a chunk must reach to end at a content-defined cut
+    pub fn min_chunk_entropy(mut self, entropy: Option<f64>) -> Self {
+        self.config.min_chunk_entropy = entropy;
+        self
+    }
+
     /// Build the configuration, validating parameters
     pub fn build(self) -> Result<ChunkingConfig> {
-        self.config.validate()?;
-        Ok(self.config)
+        let config = self.resolve();
+        config.validate()?;
+        Ok(config)
     }
 
     /// Build the configuration without validation
     pub fn build_unchecked(self) -> ChunkingConfig {
+        self.resolve()
+    }
+
+    /// Finalize the configuration
+    ///
+    /// If `avg_block_size` was never set and the default falls outside the
+    /// configured min/max range, it is moved to the midpoint of that range so
+    /// callers that predate the avg check keep building valid configurations.
+    fn resolve(mut self) -> ChunkingConfig {
+        let config = &mut self.config;
+        let out_of_range = config.avg_block_size < config.min_block_size
+            || config.avg_block_size > config.max_block_size;
+
+        if !self.avg_explicit && out_of_range && config.min_block_size <= config.max_block_size {
+            config.avg_block_size = config.min_block_size + (config.max_block_size - config.min_block_size) / 2;
+        }
+
         self.config
     }
 }
@@ -174,6 +881,16 @@ mod tests {
         assert!(config.validate().is_ok());
     }
 
+    #[test]
+    fn test_const_new() {
+        const SMALL: ChunkingConfig = ChunkingConfig::const_new(5, 50, 256, SeqOpMode::Decreasing, 512, 1024, 4096);
+        assert!(SMALL.validate().is_ok());
+        assert_eq!(SMALL.op_mode(), SeqOpMode::Decreasing);
+        assert_eq!((SMALL.min_block_size(), SMALL.avg_block_size(), SMALL.max_block_size()), (512, 1024, 4096));
+        assert_eq!(SMALL.fallback(), FallbackPolicy::default());
+        assert_eq!(SMALL.transform(), SlopeTransform::default());
+    }
+
     #[test]
     fn test_builder_pattern() {
         let config = ChunkingConfig::builder()
@@ -208,4 +925,249 @@ mod tests {
         
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_jump_size_ceiling() {
+        assert!(ChunkingConfig::builder().jump_size(MAX_JUMP_SIZE).build().is_ok());
+        assert_eq!(
+            ChunkingConfig::builder().jump_size(u64::MAX).build().unwrap_err(),
+            ChunkingError::invalid_config(format!("jump_size must be <= {}", MAX_JUMP_SIZE))
+        );
+    }
+
+    #[test]
+    fn test_warnings() {
+        assert!(ChunkingConfig::default().warnings().is_empty());
+        for preset in [ChunkingConfig::preset_backup(), ChunkingConfig::preset_small_files(), ChunkingConfig::preset_large_media()] {
+            assert!(preset.warnings().is_empty());
+        }
+
+        let narrow = ChunkingConfig::builder()
+            .min_block_size(100)
+            .avg_block_size(150)
+            .max_block_size(200)
+            .seq_threshold(50)
+            .build()
+            .unwrap();
+        let warnings = narrow.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("(100)"));
+
+        let gear = ChunkingConfig { cut_strategy: CutStrategy::Gear { mask: 0xff }, ..narrow };
+        assert!(gear.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_avg_below_min() {
+        let result = ChunkingConfig::builder()
+            .min_block_size(4096)
+            .avg_block_size(2048)
+            .build();
+
+        assert_eq!(
+            result.unwrap_err(),
+            ChunkingError::invalid_config("avg_block_size must be >= min_block_size")
+        );
+    }
+
+    #[test]
+    fn test_avg_above_max() {
+        let result = ChunkingConfig::builder()
+            .max_block_size(16384)
+            .avg_block_size(100000)
+            .build();
+
+        assert_eq!(
+            result.unwrap_err(),
+            ChunkingError::invalid_config("avg_block_size must be <= max_block_size")
+        );
+    }
+
+    #[test]
+    fn test_valid_avg() {
+        let config = ChunkingConfig::builder()
+            .min_block_size(2048)
+            .avg_block_size(4096)
+            .max_block_size(8192)
+            .build()
+            .unwrap();
+
+        assert_eq!(config.avg_block_size(), 4096);
+    }
+
+    #[test]
+    fn test_unset_avg_moves_to_midpoint() {
+        let config = ChunkingConfig::builder()
+            .min_block_size(32768)
+            .max_block_size(65536)
+            .build()
+            .unwrap();
+
+        assert_eq!(config.avg_block_size(), 49152);
+    }
+
+    #[test]
+    fn test_parse_kv() {
+        let config =
+            ChunkingConfig::parse_kv("seq_threshold=10, min_block=2K,max_block_size=32768,op_mode=decreasing")
+                .unwrap();
+
+        assert_eq!(config.seq_threshold(), 10);
+        assert_eq!(config.min_block_size(), 2048);
+        assert_eq!(config.max_block_size(), 32768);
+        assert_eq!(config.op_mode(), SeqOpMode::Decreasing);
+        assert_eq!(config.jump_size(), DEFAULT_JUMP_SIZE);
+        assert_eq!(config.small_input_policy(), SmallInputPolicy::SingleChunk);
+
+        let config = ChunkingConfig::parse_kv("small_input_policy=error").unwrap();
+        assert_eq!(config.small_input_policy(), SmallInputPolicy::Error);
+    }
+
+    #[test]
+    fn test_parse_kv_unknown_key() {
+        let result = ChunkingConfig::parse_kv("seq_threshold=10,window=4");
+        assert_eq!(
+            result.unwrap_err(),
+            ChunkingError::invalid_config("unknown key 'window'")
+        );
+    }
+
+    #[test]
+    fn test_parse_kv_optional() {
+        let config = ChunkingConfig::parse_kv("leading_sentinel=7,max_size_ratio=1.5,max_scan_distance=none").unwrap();
+        assert_eq!(config.leading_sentinel, Some(7));
+        assert_eq!(config.max_size_ratio, Some(1.5));
+        assert_eq!(config.max_scan_distance, None);
+
+        let config = ChunkingConfig::parse_kv("leading_sentinel=none,max_consecutive_jumps=3").unwrap();
+        assert_eq!(config.leading_sentinel, None);
+        assert_eq!(config.max_consecutive_jumps, Some(3));
+    }
+
+    #[test]
+    fn test_parse_kv_bad_value() {
+        assert!(ChunkingConfig::parse_kv("seq_threshold=ten").is_err());
+        assert!(ChunkingConfig::parse_kv("op_mode=sideways").is_err());
+        assert!(ChunkingConfig::parse_kv("min_block=4Q").is_err());
+        assert!(ChunkingConfig::parse_kv("seq_threshold").is_err());
+        assert!(ChunkingConfig::parse_kv("max_size_ratio=wide").is_err());
+        assert!(ChunkingConfig::parse_kv("fast_first_chunk=yes").is_err());
+        // Parsed values are still validated
+        assert!(ChunkingConfig::parse_kv("min_block=8K,max_block=4K").is_err());
+    }
+
+    #[test]
+    fn test_presets_validate() {
+        for config in [
+            ChunkingConfig::preset_backup(),
+            ChunkingConfig::preset_small_files(),
+            ChunkingConfig::preset_large_media(),
+        ] {
+            config.validate().unwrap();
+        }
+
+        let media = ChunkingConfig::preset_large_media();
+        assert_eq!(media.min_block_size(), 64 * 1024);
+        assert_eq!(media.max_block_size(), 1024 * 1024);
+    }
+
+    #[test]
+    fn test_target_avg() {
+        use crate::utils::TestDataGenerator;
+
+        let config = ChunkingConfig::builder().target_avg(DEFAULT_AVG_BLOCK_SIZE).build().unwrap();
+        assert_eq!(config.min_block_size(), DEFAULT_AVG_BLOCK_SIZE / 4);
+        assert_eq!(config.max_block_size(), DEFAULT_AVG_BLOCK_SIZE * 4);
+        assert_eq!(config.seq_threshold(), DEFAULT_SEQ_THRESHOLD);
+
+        // Explicit setters after target_avg win
+        let config = ChunkingConfig::builder().target_avg(1 << 20).seq_threshold(4).build().unwrap();
+        assert_eq!(config.seq_threshold(), 4);
+
+        let data = TestDataGenerator::generate_pseudo_random(8 << 20, 5);
+        for avg in [8 * 1024, 64 * 1024] {
+            let config = ChunkingConfig::builder().target_avg(avg).build().unwrap();
+            let chunker = crate::SeqChunking::from_config(config);
+            let actual = (data.len() / chunker.chunk_all(&data).count()) as u64;
+            assert!(actual > avg / 2 && actual < avg * 2, "target {} got {}", avg, actual);
+        }
+    }
+
+    #[test]
+    fn test_random_valid() {
+        for seed in 0..1000 {
+            let config = ChunkingConfig::random_valid(seed);
+            assert!(config.validate().is_ok(), "seed {}: {:?}", seed, config);
+        }
+
+        let a = ChunkingConfig::random_valid(7);
+        let b = ChunkingConfig::random_valid(7);
+        assert_eq!(format!("{:?}", a), format!("{:?}", b));
+    }
+
+    #[test]
+    fn test_parse_cut_strategy() {
+        assert_eq!("slope".parse::<CutStrategy>().unwrap(), CutStrategy::Slope);
+        assert_eq!("gear:0x1fff".parse::<CutStrategy>().unwrap(), CutStrategy::Gear { mask: 0x1fff });
+        assert_eq!("gear:255".parse::<CutStrategy>().unwrap(), CutStrategy::Gear { mask: 255 });
+        assert!("gear".parse::<CutStrategy>().is_err());
+        assert!("gear:0xzz".parse::<CutStrategy>().is_err());
+
+        let config = ChunkingConfig::parse_kv("cut_strategy=gear:0xff").unwrap();
+        assert_eq!(config.cut_strategy(), CutStrategy::Gear { mask: 0xff });
+    }
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("4096").unwrap(), 4096);
+        assert_eq!(parse_size("4K").unwrap(), 4096);
+        assert_eq!(parse_size("16KiB").unwrap(), 16384);
+        assert_eq!(parse_size("1m").unwrap(), 1 << 20);
+        assert!(parse_size("").is_err());
+        assert!(parse_size("K").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_from_json() {
+        let json = r#"{
+            "seq_threshold": 8,
+            "jump_trigger": 50,
+            "jump_size": 256,
+            "op_mode": "decreasing",
+            "min_block_size": 2048,
+            "avg_block_size": 4096,
+            "max_block_size": 8192
+        }"#;
+
+        let config = ChunkingConfig::from_json(json).unwrap();
+        assert_eq!(config.seq_threshold(), 8);
+        assert_eq!(config.op_mode(), SeqOpMode::Decreasing);
+
+        let roundtrip = serde_json::to_string(&config).unwrap();
+        assert!(roundtrip.contains("\"op_mode\":\"decreasing\""));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_from_json_invalid() {
+        let json = r#"{
+            "seq_threshold": 5,
+            "jump_trigger": 50,
+            "jump_size": 256,
+            "op_mode": "increasing",
+            "min_block_size": 8192,
+            "avg_block_size": 8192,
+            "max_block_size": 4096
+        }"#;
+
+        let result = ChunkingConfig::from_json(json);
+        assert!(matches!(result, Err(ChunkingError::InvalidConfig(_))));
+        assert!(ChunkingConfig::from_json("not json").is_err());
+
+        // Missing fields fall back to their defaults
+        let partial = ChunkingConfig::from_json(r#"{"seq_threshold": 7}"#).unwrap();
+        assert_eq!(partial.seq_threshold(), 7);
+        assert_eq!(partial.max_block_size(), DEFAULT_MAX_BLOCK_SIZE);
+    }
 }
\ No newline at end of file
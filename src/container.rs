@@ -0,0 +1,236 @@
+//! Binary container storing chunks with a per-chunk compression method.
+//!
+//! Layout (all integers little-endian):
+//!
+//! - header: magic `SQCC`, version `u8`, chunk count `u64`
+//! - per chunk: start `u64`, raw length `u64`, method `u8`, stored length `u64`,
+//!   then the stored bytes
+//!
+//! When writing, every compressor enabled at build time is tried and the
+//! smallest output wins; a chunk is stored raw unless compression shrinks it.
+
+use crate::chunker::{Chunk, OwnedChunk};
+use crate::error::{ChunkingError, Result};
+use std::io::{Read, Write};
+
+const MAGIC: &[u8; 4] = b"SQCC";
+const VERSION: u8 = 1;
+
+/// Compression level used for zstd-compressed chunks
+#[cfg(feature = "zstd")]
+const ZSTD_LEVEL: i32 = 3;
+
+/// Compression applied to a chunk stored in the container
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CompressionMethod {
+    /// Stored raw
+    None,
+    /// Compressed with zstd (`zstd` feature)
+    Zstd,
+    /// Compressed with gzip (`gzip` feature)
+    Gzip,
+}
+
+impl CompressionMethod {
+    fn to_byte(self) -> u8 {
+        match self {
+            CompressionMethod::None => 0,
+            CompressionMethod::Zstd => 1,
+            CompressionMethod::Gzip => 2,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Result<Self> {
+        match byte {
+            0 => Ok(CompressionMethod::None),
+            1 => Ok(CompressionMethod::Zstd),
+            2 => Ok(CompressionMethod::Gzip),
+            other => Err(ChunkingError::invalid_input(format!("unknown compression method {}", other))),
+        }
+    }
+}
+
+/// Write chunks to a container, compressing each one only if that makes it smaller
+///
+/// Returns the method chosen for every chunk, in order.
+pub fn write_chunks_container<W: Write>(mut writer: W, chunks: &[Chunk<'_>]) -> Result<Vec<CompressionMethod>> {
+    writer.write_all(MAGIC)?;
+    writer.write_all(&[VERSION])?;
+    writer.write_all(&(chunks.len() as u64).to_le_bytes())?;
+
+    let mut methods = Vec::with_capacity(chunks.len());
+    for chunk in chunks {
+        let (method, stored) = smallest_encoding(chunk.data)?;
+        let stored = stored.as_deref().unwrap_or(chunk.data);
+
+        writer.write_all(&(chunk.start as u64).to_le_bytes())?;
+        writer.write_all(&(chunk.len as u64).to_le_bytes())?;
+        writer.write_all(&[method.to_byte()])?;
+        writer.write_all(&(stored.len() as u64).to_le_bytes())?;
+        writer.write_all(stored)?;
+        methods.push(method);
+    }
+
+    writer.flush()?;
+    Ok(methods)
+}
+
+/// Read every chunk from a container, decompressing as recorded per chunk
+pub fn read_chunks_container<R: Read>(mut reader: R) -> Result<Vec<OwnedChunk>> {
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(ChunkingError::invalid_input("not a chunk container"));
+    }
+
+    let version = read_u8(&mut reader)?;
+    if version != VERSION {
+        return Err(ChunkingError::invalid_input(format!("unsupported container version {}", version)));
+    }
+
+    let count = read_u64(&mut reader)?;
+    let mut chunks = Vec::new();
+    for _ in 0..count {
+        let start = read_u64(&mut reader)? as usize;
+        let raw_len = read_u64(&mut reader)? as usize;
+        let method = CompressionMethod::from_byte(read_u8(&mut reader)?)?;
+        let stored_len = read_u64(&mut reader)?;
+
+        let mut stored = Vec::new();
+        (&mut reader).take(stored_len).read_to_end(&mut stored)?;
+        if stored.len() as u64 != stored_len {
+            return Err(ChunkingError::invalid_input("truncated chunk container"));
+        }
+
+        let data = decode(method, stored)?;
+        if data.len() != raw_len {
+            return Err(ChunkingError::invalid_input(format!(
+                "chunk at {} decoded to {} bytes, expected {}",
+                start,
+                data.len(),
+                raw_len
+            )));
+        }
+        chunks.push(OwnedChunk::new(data, start));
+    }
+
+    Ok(chunks)
+}
+
+/// Pick the smallest encoding of `data`; `None` bytes means store it raw
+fn smallest_encoding(data: &[u8]) -> Result<(CompressionMethod, Option<Vec<u8>>)> {
+    #[allow(unused_mut)]
+    let mut best: (CompressionMethod, Option<Vec<u8>>) = (CompressionMethod::None, None);
+    #[allow(unused_mut, unused_variables)]
+    let mut consider = |method, encoded: Vec<u8>| {
+        let best_len = best.1.as_ref().map_or(data.len(), Vec::len);
+        if encoded.len() < best_len {
+            best = (method, Some(encoded));
+        }
+    };
+
+    #[cfg(feature = "zstd")]
+    consider(CompressionMethod::Zstd, zstd::stream::encode_all(data, ZSTD_LEVEL)?);
+
+    #[cfg(feature = "gzip")]
+    {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(data)?;
+        consider(CompressionMethod::Gzip, encoder.finish()?);
+    }
+
+    Ok(best)
+}
+
+fn decode(method: CompressionMethod, stored: Vec<u8>) -> Result<Vec<u8>> {
+    match method {
+        CompressionMethod::None => Ok(stored),
+        #[cfg(feature = "zstd")]
+        CompressionMethod::Zstd => Ok(zstd::stream::decode_all(&stored[..])?),
+        #[cfg(feature = "gzip")]
+        CompressionMethod::Gzip => {
+            let mut data = Vec::new();
+            flate2::read::GzDecoder::new(&stored[..]).read_to_end(&mut data)?;
+            Ok(data)
+        }
+        #[allow(unreachable_patterns)]
+        other => Err(ChunkingError::invalid_input(format!(
+            "chunk compressed with {:?}, which this build does not support",
+            other
+        ))),
+    }
+}
+
+fn read_u8<R: Read>(reader: &mut R) -> Result<u8> {
+    let mut buf = [0u8; 1];
+    reader.read_exact(&mut buf)?;
+    Ok(buf[0])
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> Result<u64> {
+    let mut buf = [0u8; 8];
+    reader.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunker::SeqChunking;
+    use crate::utils::{TestDataGenerator, reconstruct};
+
+    fn mixed_data() -> Vec<u8> {
+        let mut data = TestDataGenerator::generate_pseudo_random(100_000, 41);
+        data.extend(core::iter::repeat_n(b'a', 100_000));
+        data.extend(TestDataGenerator::generate_pseudo_random(100_000, 42));
+        data
+    }
+
+    #[test]
+    fn test_container_roundtrip() {
+        let data = mixed_data();
+        let chunker = SeqChunking::new();
+        let chunks: Vec<_> = chunker.chunk_all(&data).collect();
+
+        let mut container = Vec::new();
+        let methods = write_chunks_container(&mut container, &chunks).unwrap();
+        assert_eq!(methods.len(), chunks.len());
+
+        let read = read_chunks_container(&container[..]).unwrap();
+        let expected: Vec<OwnedChunk> = chunks.iter().map(|c| c.to_owned_chunk()).collect();
+        assert_eq!(read, expected);
+        assert_eq!(reconstruct(&read), data);
+    }
+
+    #[cfg(any(feature = "zstd", feature = "gzip"))]
+    #[test]
+    fn test_container_per_chunk_methods() {
+        let data = mixed_data();
+        let chunker = SeqChunking::new();
+        let chunks: Vec<_> = chunker.chunk_all(&data).collect();
+
+        let mut container = Vec::new();
+        let methods = write_chunks_container(&mut container, &chunks).unwrap();
+
+        // Random chunks are stored raw, the run of 'a' is compressed
+        assert_eq!(methods[0], CompressionMethod::None);
+        assert_eq!(*methods.last().unwrap(), CompressionMethod::None);
+        assert!(methods.iter().any(|&m| m != CompressionMethod::None));
+        assert!(container.len() < data.len());
+
+        assert_eq!(reconstruct(&read_chunks_container(&container[..]).unwrap()), data);
+    }
+
+    #[test]
+    fn test_container_rejects_garbage() {
+        assert!(read_chunks_container(&b"NOPE\x01"[..]).is_err());
+
+        let data = mixed_data();
+        let chunker = SeqChunking::new();
+        let chunks: Vec<_> = chunker.chunk_all(&data).collect();
+        let mut container = Vec::new();
+        write_chunks_container(&mut container, &chunks).unwrap();
+        container.truncate(container.len() - 10);
+        assert!(read_chunks_container(&container[..]).is_err());
+    }
+}
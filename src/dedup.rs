@@ -0,0 +1,288 @@
+//! Deduplication analysis helpers and a minimal chunk store.
+
+use crate::chunker::{Chunk, SeqChunking};
+use crate::hashing::{HASH_SIZE, hash_bytes};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// In-memory content-addressed store keeping one copy of each distinct chunk
+#[derive(Debug, Clone, Default)]
+pub struct ChunkStore {
+    chunks: HashMap<[u8; HASH_SIZE], Vec<u8>>,
+    stored_bytes: usize,
+}
+
+impl ChunkStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store a chunk under its hash, returning `true` if it was not stored yet
+    pub fn insert_chunk(&mut self, hash: [u8; HASH_SIZE], data: &[u8]) -> bool {
+        if self.chunks.contains_key(&hash) {
+            return false;
+        }
+        self.stored_bytes += data.len();
+        self.chunks.insert(hash, data.to_vec());
+        true
+    }
+
+    /// Get a stored chunk by its hash
+    pub fn get(&self, hash: &[u8; HASH_SIZE]) -> Option<&[u8]> {
+        self.chunks.get(hash).map(Vec::as_slice)
+    }
+
+    /// Number of distinct chunks stored
+    pub fn len(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Whether the store is empty
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    /// Total size of the distinct chunks stored
+    pub fn stored_bytes(&self) -> usize {
+        self.stored_bytes
+    }
+}
+
+/// Interner sharing one `Arc<[u8]>` between all chunks with the same content
+#[derive(Debug, Clone, Default)]
+pub struct ChunkInterner {
+    chunks: HashMap<[u8; HASH_SIZE], Arc<[u8]>>,
+}
+
+impl ChunkInterner {
+    /// Create an empty interner
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the shared bytes of the chunk, storing them on first sight
+    pub fn intern(&mut self, chunk: &Chunk<'_>) -> Arc<[u8]> {
+        self.chunks
+            .entry(hash_bytes(chunk.data))
+            .or_insert_with(|| Arc::from(chunk.data))
+            .clone()
+    }
+
+    /// Number of distinct chunks interned
+    pub fn len(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Whether nothing has been interned
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+}
+
+/// Deduplication summary of a single input
+#[derive(Debug, Clone, PartialEq)]
+pub struct DedupStats {
+    /// Number of distinct chunks
+    pub unique_chunks: usize,
+    /// Number of chunks whose content already appeared earlier in the input
+    pub duplicate_chunks: usize,
+    /// Bytes covered by duplicate chunks, which need not be stored again
+    pub bytes_saved: usize,
+    /// Fraction of the input's bytes saved by deduplication (0.0 to 1.0)
+    pub dedup_ratio: f64,
+}
+
+impl SeqChunking {
+    /// Chunk the data, hash every chunk and report how much deduplicates
+    pub fn dedup_stats(&self, data: &[u8]) -> DedupStats {
+        let mut store = ChunkStore::new();
+        let mut duplicate_chunks = 0;
+        let mut bytes_saved = 0;
+
+        for chunk in self.chunk_all(data) {
+            if !store.insert_chunk(hash_bytes(chunk.data), chunk.data) {
+                duplicate_chunks += 1;
+                bytes_saved += chunk.len;
+            }
+        }
+
+        let dedup_ratio = if data.is_empty() {
+            0.0
+        } else {
+            bytes_saved as f64 / data.len() as f64
+        };
+
+        DedupStats {
+            unique_chunks: store.len(),
+            duplicate_chunks,
+            bytes_saved,
+            dedup_ratio,
+        }
+    }
+}
+
+/// Overlap between the chunks of two inputs, treating B as an increment over A
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrossOverlap {
+    /// Number of chunks in A
+    pub chunks_a: usize,
+    /// Number of chunks in B
+    pub chunks_b: usize,
+    /// Number of distinct chunks of B that already exist in A
+    pub shared_unique_chunks: usize,
+    /// Bytes of B covered by chunks that already exist in A
+    pub shared_bytes: usize,
+    /// Bytes of distinct chunks of B that would need to be stored on top of A
+    pub new_bytes: usize,
+    /// Fraction of B's bytes already present in A (0.0 to 1.0)
+    pub dedup_ratio: f64,
+}
+
+/// Chunk both inputs and report how much of B is already covered by A
+pub fn cross_file_overlap(chunker: &SeqChunking, file_a: &[u8], file_b: &[u8]) -> CrossOverlap {
+    let mut chunks_a = 0;
+    let mut hashes_a: HashSet<[u8; HASH_SIZE]> = HashSet::new();
+    for chunk in chunker.chunk_all(file_a) {
+        chunks_a += 1;
+        hashes_a.insert(hash_bytes(chunk.data));
+    }
+
+    let mut chunks_b = 0;
+    let mut shared_bytes = 0;
+    let mut new_bytes = 0;
+    let mut seen_b: HashSet<[u8; HASH_SIZE]> = HashSet::new();
+    let mut shared_unique_chunks = 0;
+
+    for chunk in chunker.chunk_all(file_b) {
+        chunks_b += 1;
+        let hash = hash_bytes(chunk.data);
+        let in_a = hashes_a.contains(&hash);
+
+        if in_a {
+            shared_bytes += chunk.len;
+        }
+
+        if seen_b.insert(hash) {
+            if in_a {
+                shared_unique_chunks += 1;
+            } else {
+                new_bytes += chunk.len;
+            }
+        }
+    }
+
+    let dedup_ratio = if file_b.is_empty() {
+        0.0
+    } else {
+        shared_bytes as f64 / file_b.len() as f64
+    };
+
+    CrossOverlap {
+        chunks_a,
+        chunks_b,
+        shared_unique_chunks,
+        shared_bytes,
+        new_bytes,
+        dedup_ratio,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDataGenerator;
+
+    #[test]
+    fn test_cross_file_overlap_small_edit() {
+        let chunker = SeqChunking::new();
+        let file_a = TestDataGenerator::generate_pseudo_random(1_000_000, 5);
+        let mut file_b = file_a.clone();
+        file_b.splice(500_000..500_000, b"inserted edit".iter().copied());
+
+        let overlap = cross_file_overlap(&chunker, &file_a, &file_b);
+        assert!(overlap.dedup_ratio > 0.9);
+        assert!(overlap.shared_unique_chunks > 0);
+        assert!(overlap.new_bytes < file_b.len() / 10);
+        assert_eq!(overlap.shared_bytes + overlap.new_bytes, file_b.len());
+    }
+
+    #[test]
+    fn test_chunk_store_insert() {
+        let mut store = ChunkStore::new();
+        let hash = hash_bytes(b"chunk");
+        assert!(store.insert_chunk(hash, b"chunk"));
+        assert!(!store.insert_chunk(hash, b"chunk"));
+        assert_eq!(store.len(), 1);
+        assert_eq!(store.stored_bytes(), 5);
+        assert_eq!(store.get(&hash), Some(&b"chunk"[..]));
+    }
+
+    #[test]
+    fn test_chunk_interner_shares_duplicates() {
+        let chunker = SeqChunking::new();
+        let block = TestDataGenerator::generate_pseudo_random(50_000, 12);
+        let mut data = block.clone();
+        data.extend_from_slice(&block);
+
+        let mut interner = ChunkInterner::new();
+        let chunks: Vec<_> = chunker.chunk_all(&data).collect();
+        let handles: Vec<Arc<[u8]>> = chunks.iter().map(|chunk| interner.intern(chunk)).collect();
+
+        let mut shared = 0;
+        for (i, chunk) in chunks.iter().enumerate() {
+            assert_eq!(&handles[i][..], chunk.data);
+            if let Some(first) = chunks[..i].iter().position(|earlier| earlier.data == chunk.data) {
+                assert!(Arc::ptr_eq(&handles[first], &handles[i]));
+                shared += 1;
+            }
+        }
+        assert!(shared > 0);
+        assert_eq!(interner.len(), chunks.len() - shared);
+
+        let distinct = chunker.chunk_all(&block).next().unwrap();
+        let other = TestDataGenerator::generate_pseudo_random(10_000, 13);
+        let other = chunker.chunk_all(&other).next().unwrap();
+        assert!(!Arc::ptr_eq(&interner.intern(&distinct), &interner.intern(&other)));
+    }
+
+    #[test]
+    fn test_dedup_stats_repeated_block() {
+        let config = crate::ChunkingConfig::builder()
+            .min_block_size(512)
+            .avg_block_size(1024)
+            .max_block_size(4096)
+            .seq_threshold(3)
+            .build()
+            .unwrap();
+        let chunker = SeqChunking::from_config(config);
+
+        let block = TestDataGenerator::generate_pseudo_random(20_000, 7);
+        let mut data = TestDataGenerator::generate_pseudo_random(50_000, 8);
+        data.extend_from_slice(&block);
+        data.extend(TestDataGenerator::generate_pseudo_random(50_000, 9));
+        data.extend_from_slice(&block);
+        data.extend(TestDataGenerator::generate_pseudo_random(50_000, 10));
+
+        let stats = chunker.dedup_stats(&data);
+        assert!(stats.duplicate_chunks > 0);
+        // Boundaries resynchronize early in the second copy, so most of it is saved
+        assert!(stats.bytes_saved > 15_000 && stats.bytes_saved <= 20_000, "{:?}", stats);
+        assert!(stats.dedup_ratio > 0.08 && stats.dedup_ratio < 0.12);
+
+        let unique = chunker.dedup_stats(&TestDataGenerator::generate_pseudo_random(100_000, 11));
+        assert_eq!(unique.duplicate_chunks, 0);
+        assert_eq!(unique.dedup_ratio, 0.0);
+    }
+
+    #[test]
+    fn test_cross_file_overlap_disjoint() {
+        let chunker = SeqChunking::new();
+        let file_a = TestDataGenerator::generate_pseudo_random(200_000, 1);
+        let file_b = TestDataGenerator::generate_pseudo_random(200_000, 2);
+
+        let overlap = cross_file_overlap(&chunker, &file_a, &file_b);
+        assert_eq!(overlap.shared_bytes, 0);
+        assert_eq!(overlap.dedup_ratio, 0.0);
+    }
+}
@@ -0,0 +1,46 @@
+//! Element types that can be chunked.
+
+use core::fmt::Debug;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// An element of a stream that the chunker compares for slopes
+///
+/// Slopes are computed on the `i64` widening of each element so that the
+/// difference of two neighbours never overflows. Differences of 32-bit
+/// elements stay below 2^32 in magnitude, and the second difference
+/// transform below 2^34, far from the `i64` limits. This trait is sealed
+/// and implemented for the primitive integer types up to 32 bits.
+pub trait SeqElement: sealed::Sealed + Copy + Ord + Debug {
+    /// Widen the element for slope comparison
+    fn to_i64(self) -> i64;
+
+    /// Reduce a difference of two elements to the shortest signed step modulo the element width
+    ///
+    /// For `u8`, `0 - 255` becomes `+1`: the counter wrapped around.
+    fn wrap_diff(diff: i64) -> i64;
+}
+
+macro_rules! impl_seq_element {
+    ($($t:ty => $signed:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl SeqElement for $t {
+                #[inline]
+                fn to_i64(self) -> i64 {
+                    self as i64
+                }
+
+                #[inline]
+                fn wrap_diff(diff: i64) -> i64 {
+                    diff as $signed as i64
+                }
+            }
+        )*
+    };
+}
+
+impl_seq_element!(u8 => i8, u16 => i16, u32 => i32, i8 => i8, i16 => i16, i32 => i32);
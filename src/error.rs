@@ -1,12 +1,15 @@
 //! Error handling for the chunking library.
 
-use std::fmt;
+use alloc::string::String;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::sync::Arc;
 
 /// Result type alias for this crate
-pub type Result<T> = std::result::Result<T, ChunkingError>;
+pub type Result<T> = core::result::Result<T, ChunkingError>;
 
 /// Errors that can occur during chunking operations
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub enum ChunkingError {
     /// Configuration validation error
     InvalidConfig(String),
@@ -14,8 +17,16 @@ pub enum ChunkingError {
     InvalidInput(String),
     /// Internal processing error
     ProcessingError(String),
-    /// I/O related error
+    /// I/O related error described by a message only
     IoError(String),
+    /// I/O error keeping the underlying [`std::io::Error`] as its source
+    #[cfg(feature = "std")]
+    Io {
+        /// What was being done when the error occurred (may be empty)
+        context: String,
+        /// The underlying I/O error
+        source: Arc<std::io::Error>,
+    },
 }
 
 impl ChunkingError {
@@ -35,11 +46,44 @@ impl ChunkingError {
     }
 
     /// Create a new IoError
+    #[cfg_attr(
+        feature = "std",
+        deprecated(note = "use `ChunkingError::io` to keep the underlying error as the source")
+    )]
     pub fn io_error<S: Into<String>>(msg: S) -> Self {
         ChunkingError::IoError(msg.into())
     }
+
+    /// Wrap an I/O error with a description of what was being done
+    #[cfg(feature = "std")]
+    pub fn io<S: Into<String>>(context: S, err: std::io::Error) -> Self {
+        ChunkingError::Io {
+            context: context.into(),
+            source: Arc::new(err),
+        }
+    }
 }
 
+impl PartialEq for ChunkingError {
+    fn eq(&self, other: &Self) -> bool {
+        use ChunkingError::*;
+        match (self, other) {
+            (InvalidConfig(a), InvalidConfig(b))
+            | (InvalidInput(a), InvalidInput(b))
+            | (ProcessingError(a), ProcessingError(b))
+            | (IoError(a), IoError(b)) => a == b,
+            // io::Error has no equality; compare what it reports
+            #[cfg(feature = "std")]
+            (Io { context: a, source: sa }, Io { context: b, source: sb }) => {
+                a == b && sa.kind() == sb.kind() && sa.to_string() == sb.to_string()
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ChunkingError {}
+
 impl fmt::Display for ChunkingError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -47,15 +91,28 @@ impl fmt::Display for ChunkingError {
             ChunkingError::InvalidInput(msg) => write!(f, "Invalid input: {}", msg),
             ChunkingError::ProcessingError(msg) => write!(f, "Processing error: {}", msg),
             ChunkingError::IoError(msg) => write!(f, "I/O error: {}", msg),
+            #[cfg(feature = "std")]
+            ChunkingError::Io { context, source } if context.is_empty() => write!(f, "I/O error: {}", source),
+            #[cfg(feature = "std")]
+            ChunkingError::Io { context, source } => write!(f, "I/O error: {}: {}", context, source),
         }
     }
 }
 
-impl std::error::Error for ChunkingError {}
+#[cfg(feature = "std")]
+impl std::error::Error for ChunkingError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ChunkingError::Io { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
 
+#[cfg(feature = "std")]
 impl From<std::io::Error> for ChunkingError {
     fn from(err: std::io::Error) -> Self {
-        ChunkingError::IoError(err.to_string())
+        ChunkingError::io("", err)
     }
 }
 
@@ -69,14 +126,22 @@ mod tests {
         assert_eq!(err.to_string(), "Invalid configuration: test message");
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_error_from_io() {
+        use std::error::Error;
+
         let io_err = std::io::Error::new(std::io::ErrorKind::NotFound, "file not found");
         let chunk_err: ChunkingError = io_err.into();
-        
-        match chunk_err {
-            ChunkingError::IoError(_) => (),
-            _ => panic!("Expected IoError"),
-        }
+        assert_eq!(chunk_err.to_string(), "I/O error: file not found");
+
+        // The original error is kept as the source, with its kind
+        let source = chunk_err.source().expect("source");
+        let io_err = source.downcast_ref::<std::io::Error>().expect("io::Error source");
+        assert_eq!(io_err.kind(), std::io::ErrorKind::NotFound);
+
+        let with_context = ChunkingError::io("Failed to open file", std::io::ErrorKind::NotFound.into());
+        assert_eq!(with_context.to_string(), "I/O error: Failed to open file: entity not found");
+        assert_ne!(with_context, chunk_err);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,76 @@
+//! Pluggable hash functions for chunk IDs.
+
+use alloc::vec::Vec;
+
+/// Hash function producing chunk IDs for a content-addressed store
+pub trait ChunkHasher {
+    /// Hash the chunk's bytes into its ID
+    fn hash(&self, data: &[u8]) -> Vec<u8>;
+
+    /// Length of the IDs produced by [`hash`](Self::hash), in bytes
+    fn id_len(&self) -> usize;
+}
+
+impl<H: ChunkHasher + ?Sized> ChunkHasher for &H {
+    fn hash(&self, data: &[u8]) -> Vec<u8> {
+        (**self).hash(data)
+    }
+
+    fn id_len(&self) -> usize {
+        (**self).id_len()
+    }
+}
+
+/// SHA-256 chunk IDs (`hashing` feature), the same as [`crate::hashing::hash_bytes`]
+#[cfg(feature = "hashing")]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Sha256Hasher;
+
+#[cfg(feature = "hashing")]
+impl ChunkHasher for Sha256Hasher {
+    fn hash(&self, data: &[u8]) -> Vec<u8> {
+        crate::hashing::hash_bytes(data).to_vec()
+    }
+
+    fn id_len(&self) -> usize {
+        crate::hashing::HASH_SIZE
+    }
+}
+
+/// BLAKE3 chunk IDs (`blake3` feature)
+#[cfg(feature = "blake3")]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Blake3Hasher;
+
+#[cfg(feature = "blake3")]
+impl ChunkHasher for Blake3Hasher {
+    fn hash(&self, data: &[u8]) -> Vec<u8> {
+        blake3::hash(data).as_bytes().to_vec()
+    }
+
+    fn id_len(&self) -> usize {
+        blake3::OUT_LEN
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "hashing")]
+    #[test]
+    fn test_sha256_hasher() {
+        use super::*;
+
+        assert_eq!(Sha256Hasher.hash(b"abc"), crate::hashing::hash_bytes(b"abc"));
+        assert_eq!(Sha256Hasher.hash(b"abc").len(), Sha256Hasher.id_len());
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn test_blake3_hasher() {
+        use super::*;
+
+        let id = Blake3Hasher.hash(b"abc");
+        assert_eq!(id.len(), Blake3Hasher.id_len());
+        assert_eq!(id[..4], [0x64, 0x37, 0xb3, 0xac]);
+    }
+}
@@ -0,0 +1,23 @@
+//! Hashing helpers for chunk identification and integrity.
+
+use sha2::{Digest, Sha256};
+
+/// Size of a chunk hash in bytes
+pub const HASH_SIZE: usize = 32;
+
+/// Compute the SHA-256 hash of the given data
+pub fn hash_bytes(data: &[u8]) -> [u8; HASH_SIZE] {
+    Sha256::digest(data).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_bytes() {
+        let hash = hash_bytes(b"abc");
+        assert_eq!(hash[..4], [0xba, 0x78, 0x16, 0xbf]);
+        assert_ne!(hash, hash_bytes(b"abd"));
+    }
+}
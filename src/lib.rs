@@ -25,15 +25,56 @@
 //! let data = b"your data here";
 //! let chunks: Vec<_> = chunker.chunk_all(data).collect();
 //! ```
+//!
+//! ## Determinism
+//!
+//! Chunk boundaries depend only on the input and the configuration. Scan
+//! positions are tracked as `u64` and slopes are computed in `i64`, so the same
+//! input produces the same boundaries on every platform, including 32-bit
+//! targets. A canary test pins the boundaries of a fixed input.
+//!
+//! ## `no_std`
+//!
+//! Disabling the default `std` feature builds the chunker, configuration and
+//! errors on `core` + `alloc`. File I/O, timing and the reader-based APIs
+//! require `std`.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 pub mod config;
 pub mod chunker;
+#[cfg(feature = "std")]
+pub mod container;
+pub mod element;
 pub mod error;
+pub mod hasher;
+#[cfg(feature = "std")]
+pub mod lines;
+pub mod streaming;
 pub mod utils;
+#[cfg(feature = "std")]
+pub mod writer;
+#[cfg(feature = "hashing")]
+pub mod hashing;
+#[cfg(all(feature = "hashing", feature = "std"))]
+pub mod dedup;
+#[cfg(feature = "rayon")]
+mod parallel;
 
-pub use config::{ChunkingConfig, SeqOpMode};
-pub use chunker::{SeqChunking, Chunk, ChunkIterator};
+pub use config::{ChunkingConfig, CutStrategy, FallbackPolicy, SeqOpMode, SlopeTransform, SmallInputPolicy};
+pub use chunker::{SeqChunking, SeqChunker, Chunk, ChunkIterator, ChunkRecord, Chunker, CutReason, OwnedChunk, ScanMetrics};
+pub use element::SeqElement;
 pub use error::{ChunkingError, Result};
+pub use hasher::ChunkHasher;
+pub use streaming::StreamingChunker;
+#[cfg(feature = "std")]
+pub use writer::ChunkingWriter;
+#[cfg(feature = "rayon")]
+pub use parallel::ParChunkIterator;
+#[cfg(feature = "bytes")]
+pub use chunker::BytesChunk;
 
 /// Default sequence length threshold
 pub const DEFAULT_SEQ_THRESHOLD: u64 = 5;
@@ -44,6 +85,12 @@ pub const DEFAULT_JUMP_TRIGGER: u64 = 50;
 /// Default jump size when trigger is hit
 pub const DEFAULT_JUMP_SIZE: u64 = 256;
 
+/// Largest accepted `jump_size`
+///
+/// A jump past the end of the buffer already ends the scan, so larger values
+/// only risk overflow in the position arithmetic.
+pub const MAX_JUMP_SIZE: u64 = 1 << 32;
+
 /// Default minimum block size
 pub const DEFAULT_MIN_BLOCK_SIZE: u64 = 4096;
 
@@ -53,6 +100,18 @@ pub const DEFAULT_AVG_BLOCK_SIZE: u64 = 8192;
 /// Default maximum block size
 pub const DEFAULT_MAX_BLOCK_SIZE: u64 = 16384;
 
+/// Default distance between the two bytes compared for a slope
+pub const DEFAULT_SLOPE_WINDOW: u64 = 1;
+
+/// Minimum size of the first chunk when `fast_first_chunk` is enabled
+pub const FAST_FIRST_CHUNK_MIN_SIZE: u64 = 256;
+
+/// Version of the cut logic, mixed into [`SeqChunking::algorithm_fingerprint`]
+///
+/// Must be bumped whenever a change to the chunker moves any boundary for an
+/// unchanged input and configuration.
+pub const ALGORITHM_VERSION: u32 = 1;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,158 @@
+//! Line-aware chunking for text read through `BufRead`.
+
+use crate::chunker::{OwnedChunk, SeqChunking};
+use crate::error::Result;
+use std::io::BufRead;
+
+/// Iterator over line-aligned chunks read from a `BufRead` source
+///
+/// Created by [`SeqChunking::chunk_lines`].
+pub struct LineChunkIterator<'a, R> {
+    chunker: &'a SeqChunking,
+    reader: R,
+    buffer: Vec<u8>,
+    offset: usize,
+    eof: bool,
+}
+
+impl<'a, R: BufRead> LineChunkIterator<'a, R> {
+    fn new(chunker: &'a SeqChunking, reader: R) -> Self {
+        Self {
+            chunker,
+            reader,
+            buffer: Vec::new(),
+            offset: 0,
+            eof: false,
+        }
+    }
+
+    /// End of the chunk for the buffered data, if it can be decided yet
+    ///
+    /// The slope cut only depends on the first `max_block_size` bytes, so it is
+    /// final once that much is buffered (or the input ended). The chunk then
+    /// extends to the end of the line containing the cut.
+    fn chunk_end(&self) -> Option<usize> {
+        let max = self.chunker.max_block_size() as usize;
+        if self.buffer.is_empty() || (self.buffer.len() < max && !self.eof) {
+            return None;
+        }
+
+        let len = self.buffer.len();
+        let cut = self
+            .chunker
+            .find_cutpoint_at(&self.buffer, len as u64, self.offset)
+            .clamp(1, len as u64) as usize;
+
+        // A FullRemaining fallback cut is only final at the end of input
+        if cut > max && !self.eof {
+            return None;
+        }
+
+        if self.buffer[cut - 1] == b'\n' {
+            return Some(cut);
+        }
+
+        match self.buffer[cut..].iter().position(|&b| b == b'\n') {
+            Some(idx) => Some(cut + idx + 1),
+            None if self.eof => Some(len),
+            None => None,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for LineChunkIterator<'_, R> {
+    type Item = Result<OwnedChunk>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(end) = self.chunk_end() {
+                let data: Vec<u8> = self.buffer.drain(..end).collect();
+                let chunk = OwnedChunk::new(data, self.offset);
+                self.offset += end;
+                return Some(Ok(chunk));
+            }
+
+            if self.eof {
+                return None;
+            }
+
+            match self.reader.read_until(b'\n', &mut self.buffer) {
+                Ok(0) => self.eof = true,
+                Ok(_) => {}
+                Err(e) => return Some(Err(e.into())),
+            }
+        }
+    }
+}
+
+impl SeqChunking {
+    /// Chunk text from a reader so that every chunk ends at a line boundary
+    ///
+    /// Each chunk ends at the first newline at or after the slope cut, so
+    /// records are never split. Only the last chunk may lack a trailing
+    /// newline, when the input does not end with one. A chunk can exceed
+    /// `max_block_size` when a line straddles the cut.
+    pub fn chunk_lines<R: BufRead>(&self, reader: R) -> LineChunkIterator<'_, R> {
+        LineChunkIterator::new(self, reader)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::ValidationUtils;
+
+    fn sample_log(lines: usize) -> Vec<u8> {
+        let mut text = Vec::new();
+        let mut state: u64 = 42;
+        for i in 0..lines {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            let width = 10 + (state >> 58) as usize;
+            text.extend_from_slice(format!("{:06} level=info msg={}\n", i, "x".repeat(width)).as_bytes());
+            text.extend((0..(state >> 60) as u8).map(|b| b'a' + b));
+            text.push(b'\n');
+        }
+        text
+    }
+
+    #[test]
+    fn test_chunk_lines_end_on_newline() {
+        let chunker = SeqChunking::new();
+        let text = sample_log(5_000);
+
+        let chunks: Vec<OwnedChunk> = chunker
+            .chunk_lines(text.as_slice())
+            .collect::<Result<_>>()
+            .unwrap();
+
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().all(|c| c.data.ends_with(b"\n")));
+
+        let borrowed: Vec<_> = chunks.iter().map(|c| c.as_chunk()).collect();
+        ValidationUtils::validate_chunk_coverage(text.len(), &borrowed).unwrap();
+        assert!(ValidationUtils::verify_chunks(&text, &borrowed).unwrap());
+    }
+
+    #[test]
+    fn test_chunk_lines_final_line_without_newline() {
+        let chunker = SeqChunking::new();
+        let mut text = sample_log(2_000);
+        text.extend_from_slice(b"trailing line without newline");
+
+        let chunks: Vec<OwnedChunk> = chunker
+            .chunk_lines(text.as_slice())
+            .collect::<Result<_>>()
+            .unwrap();
+
+        let (last, rest) = chunks.split_last().unwrap();
+        assert!(rest.iter().all(|c| c.data.ends_with(b"\n")));
+        assert!(last.data.ends_with(b"trailing line without newline"));
+        assert_eq!(last.end(), text.len());
+    }
+
+    #[test]
+    fn test_chunk_lines_empty() {
+        let chunker = SeqChunking::new();
+        assert!(chunker.chunk_lines(&b""[..]).next().is_none());
+    }
+}
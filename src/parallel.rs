@@ -0,0 +1,222 @@
+//! Parallel chunking built on rayon.
+//!
+//! The input is split into coarse super-segments that are chunked speculatively
+//! in parallel, each starting at its segment start. Since a cutpoint depends only
+//! on the bytes following the previous boundary, the true boundary chain re-syncs
+//! with a speculative chain as soon as both share a boundary. Stitching walks the
+//! true chain sequentially and adopts a segment's speculative boundaries from the
+//! first shared one, so the output is identical to sequential chunking.
+
+use crate::chunker::{ChunkIterator, OwnedChunk, SeqChunking};
+#[cfg(feature = "hashing")]
+use crate::hashing::HASH_SIZE;
+use rayon::iter::plumbing::UnindexedConsumer;
+use rayon::prelude::*;
+
+/// Minimum super-segment size, as a multiple of `max_block_size`
+const MIN_SEGMENT_BLOCKS: usize = 256;
+
+impl SeqChunking {
+    /// Chunk the data in parallel, producing the same chunks as sequential chunking
+    pub fn chunk_all_parallel(&self, data: &[u8]) -> Vec<OwnedChunk> {
+        self.par_chunks(data).collect()
+    }
+
+    /// Parallel iterator over the chunks of `data`, for use in rayon pipelines
+    ///
+    /// Boundaries are found with the same re-sync technique as
+    /// [`SeqChunking::chunk_all_parallel`] and are identical to sequential
+    /// chunking. Downstream adapters such as hashing run fused with the copy of
+    /// each chunk.
+    pub fn par_chunks<'a>(&'a self, data: &'a [u8]) -> ParChunkIterator<'a> {
+        ParChunkIterator { chunker: self, data }
+    }
+
+    /// Super-segment size used for `data_len` bytes of input
+    fn segment_size(&self, data_len: usize) -> usize {
+        let min_segment = (self.config().max_block_size as usize).saturating_mul(MIN_SEGMENT_BLOCKS);
+        (data_len / rayon::current_num_threads().max(1)).max(min_segment)
+    }
+
+    /// Chunk the data serially, then hash all chunks in parallel
+    ///
+    /// The output is identical to [`SeqChunking::chunk_all_hashed`], in order.
+    #[cfg(feature = "hashing")]
+    pub fn chunk_all_hashed_parallel(&self, data: &[u8]) -> Vec<(OwnedChunk, [u8; HASH_SIZE])> {
+        let chunks: Vec<_> = self.chunk_all(data).collect();
+        chunks
+            .into_par_iter()
+            .map(|chunk| {
+                let owned = chunk.to_owned_chunk();
+                let hash = owned.hash();
+                (owned, hash)
+            })
+            .collect()
+    }
+
+    /// Parallel chunking with an explicit super-segment size
+    #[cfg(test)]
+    fn chunk_all_parallel_with_segment(
+        &self,
+        data: &[u8],
+        segment_size: usize,
+    ) -> Vec<OwnedChunk> {
+        self.parallel_boundaries(data, segment_size)
+            .par_windows(2)
+            .map(|w| OwnedChunk::new(data[w[0]..w[1]].to_vec(), w[0]))
+            .collect()
+    }
+
+    /// All chunk boundaries of `data`, starting with 0, found segment-parallel
+    ///
+    /// With `max_size_ratio` a cut also depends on the previous chunk's size,
+    /// so chains never provably re-sync and boundaries are found sequentially.
+    fn parallel_boundaries(&self, data: &[u8], segment_size: usize) -> Vec<usize> {
+        if self.config().max_size_ratio.is_some() {
+            return core::iter::once(0).chain(self.chunk_offsets(data)).collect();
+        }
+
+        let segment_size = segment_size.max(1);
+        let segment_starts: Vec<usize> = (0..data.len()).step_by(segment_size).collect();
+
+        // Speculative boundary chains, one per segment. Each chain holds the
+        // segment start followed by chunk ends up to the first one at or past
+        // the next segment start.
+        let chains: Vec<Vec<usize>> = segment_starts
+            .par_iter()
+            .map(|&start| {
+                let limit = (start + segment_size).min(data.len());
+                let mut chain = vec![start];
+                for chunk in ChunkIterator::starting_at(data, self, start) {
+                    chain.push(chunk.end());
+                    if chunk.end() >= limit {
+                        break;
+                    }
+                }
+                chain
+            })
+            .collect();
+
+        // Stitch: follow the true chain and adopt speculative boundaries once synced
+        let mut boundaries = vec![0usize];
+        let mut position = 0usize;
+
+        for (i, chain) in chains.iter().enumerate() {
+            let limit = (segment_starts[i] + segment_size).min(data.len());
+
+            while position < limit {
+                if let Ok(idx) = chain.binary_search(&position) {
+                    boundaries.extend_from_slice(&chain[idx + 1..]);
+                    position = *boundaries.last().unwrap_or(&position);
+                    break;
+                }
+
+                match ChunkIterator::starting_at(data, self, position).next() {
+                    Some(chunk) => {
+                        position = chunk.end();
+                        boundaries.push(position);
+                    }
+                    None => break,
+                }
+            }
+        }
+
+        boundaries
+    }
+}
+
+/// Parallel iterator over owned chunks, created by [`SeqChunking::par_chunks`]
+#[derive(Debug, Clone, Copy)]
+pub struct ParChunkIterator<'a> {
+    chunker: &'a SeqChunking,
+    data: &'a [u8],
+}
+
+impl ParallelIterator for ParChunkIterator<'_> {
+    type Item = OwnedChunk;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        let data = self.data;
+        let segment_size = self.chunker.segment_size(data.len());
+        self.chunker
+            .parallel_boundaries(data, segment_size)
+            .par_windows(2)
+            .map(|w| OwnedChunk::new(data[w[0]..w[1]].to_vec(), w[0]))
+            .drive_unindexed(consumer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::TestDataGenerator;
+    use crate::{OwnedChunk, SeqChunking};
+
+    fn sequential(chunker: &SeqChunking, data: &[u8]) -> Vec<OwnedChunk> {
+        chunker.chunk_all(data).map(OwnedChunk::from).collect()
+    }
+
+    #[test]
+    fn test_parallel_matches_sequential() {
+        let chunker = SeqChunking::new();
+        let data = TestDataGenerator::generate_pseudo_random(10_000_000, 12345);
+
+        assert_eq!(chunker.chunk_all_parallel(&data), sequential(&chunker, &data));
+    }
+
+    #[test]
+    fn test_parallel_small_segments() {
+        let chunker = SeqChunking::new();
+        let data = TestDataGenerator::generate_mixed_patterns(1_000_000);
+        let expected = sequential(&chunker, &data);
+
+        for segment_size in [1, 4096, 10_000, 65_536] {
+            let chunks = chunker.chunk_all_parallel_with_segment(&data, segment_size);
+            assert_eq!(chunks, expected, "segment size {}", segment_size);
+        }
+    }
+
+    #[cfg(feature = "hashing")]
+    #[test]
+    fn test_hashed_parallel_matches_serial() {
+        let chunker = SeqChunking::new();
+        let data = TestDataGenerator::generate_pseudo_random(5_000_000, 77);
+
+        let parallel = chunker.chunk_all_hashed_parallel(&data);
+        assert_eq!(parallel, chunker.chunk_all_hashed(&data));
+        assert!(parallel.len() > 100);
+    }
+
+    #[cfg(feature = "hashing")]
+    #[test]
+    fn test_par_chunks_pipeline() {
+        use rayon::prelude::*;
+
+        let chunker = SeqChunking::new();
+        let data = TestDataGenerator::generate_pseudo_random(5_000_000, 78);
+
+        let hashes: Vec<_> = chunker.par_chunks(&data).map(|c| c.hash()).collect();
+        let expected: Vec<_> = sequential(&chunker, &data).iter().map(|c| c.hash()).collect();
+        assert_eq!(hashes, expected);
+
+        let total: usize = chunker.par_chunks(&data).map(|c| c.len).sum();
+        assert_eq!(total, data.len());
+    }
+
+    #[test]
+    fn test_parallel_max_size_ratio() {
+        let mut data = TestDataGenerator::generate_pseudo_random(300_000, 9);
+        data.extend(core::iter::repeat_n(9u8, 100_000));
+        let config = crate::ChunkingConfig::builder().max_size_ratio(Some(1.5)).build().unwrap();
+        let chunker = SeqChunking::from_config(config);
+        assert_eq!(chunker.chunk_all_parallel_with_segment(&data, 50_000), sequential(&chunker, &data));
+    }
+
+    #[test]
+    fn test_parallel_empty() {
+        let chunker = SeqChunking::new();
+        assert!(chunker.chunk_all_parallel(&[]).is_empty());
+    }
+}
@@ -0,0 +1,249 @@
+//! Stateful chunking for data that arrives incrementally.
+
+use crate::chunker::{OwnedChunk, SeqChunking};
+use crate::config::ChunkingConfig;
+use alloc::vec::Vec;
+
+/// Chunker that accepts input in arbitrary fragments
+///
+/// Bytes that have not been cut yet are buffered between calls. Slopes never
+/// read before the start of the current chunk (see
+/// [`SeqChunking::required_lookback`]), so nothing of an emitted chunk is
+/// retained. A boundary is only committed once at least `max_block_size` bytes
+/// are buffered, since a cutpoint never depends on bytes past that point. Under
+/// [`FallbackPolicy::FullRemaining`](crate::FallbackPolicy::FullRemaining) a
+/// scan without a trigger holds everything back until [`finish`](Self::finish).
+/// The chunks produced are therefore identical to [`SeqChunking::chunk_all`]
+/// over the concatenated input, regardless of how the input was fragmented.
+#[derive(Debug, Clone)]
+pub struct StreamingChunker {
+    chunker: SeqChunking,
+    buffer: Vec<u8>,
+    offset: usize,
+    prev_len: Option<usize>,
+}
+
+impl StreamingChunker {
+    /// Create a streaming chunker around the given chunker
+    pub fn new(chunker: SeqChunking) -> Self {
+        Self {
+            chunker,
+            buffer: Vec::new(),
+            offset: 0,
+            prev_len: None,
+        }
+    }
+
+    /// Create a streaming chunker with the given configuration
+    pub fn from_config(config: ChunkingConfig) -> Self {
+        Self::new(SeqChunking::from_config(config))
+    }
+
+    /// Get the underlying chunker
+    pub fn chunker(&self) -> &SeqChunking {
+        &self.chunker
+    }
+
+    /// Number of bytes buffered but not yet emitted
+    pub fn buffered_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Absolute position of the next byte to be emitted
+    pub fn position(&self) -> usize {
+        self.offset
+    }
+
+    /// Feed more bytes and return every chunk whose boundary is now committed
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<OwnedChunk> {
+        self.buffer.extend_from_slice(bytes);
+
+        let max = self.chunker.max_block_size() as usize;
+        let mut chunks = Vec::new();
+        let mut consumed = 0;
+
+        while self.buffer.len() - consumed >= max {
+            let len = self.cut_len(consumed);
+            // Only FullRemaining cuts past max_block_size, and that needs the end
+            if len > max {
+                break;
+            }
+            chunks.push(self.emit(consumed, len));
+            consumed += len;
+        }
+
+        self.buffer.drain(..consumed);
+        chunks
+    }
+
+    /// Flush the remaining buffered bytes as final chunks
+    ///
+    /// The tail may still contain content-defined boundaries, so more than one
+    /// chunk can be returned. The chunker is reset and can be reused afterwards.
+    pub fn finish(&mut self) -> Vec<OwnedChunk> {
+        let mut chunks = Vec::new();
+        let mut consumed = 0;
+
+        while consumed < self.buffer.len() {
+            let len = self.cut_len(consumed);
+            chunks.push(self.emit(consumed, len));
+            consumed += len;
+        }
+
+        self.buffer.clear();
+        self.offset = 0;
+        self.prev_len = None;
+        chunks
+    }
+
+    /// Length of the chunk starting at `consumed` within the buffer
+    fn cut_len(&self, consumed: usize) -> usize {
+        let remaining = &self.buffer[consumed..];
+        let cutpoint = self
+            .chunker
+            .find_cutpoint_at(remaining, remaining.len() as u64, self.offset);
+        let len = self.chunker.smooth_len(cutpoint as usize, self.prev_len, remaining.len());
+        len.clamp(1, remaining.len())
+    }
+
+    /// Emit the chunk of `len` bytes starting at `consumed` within the buffer
+    fn emit(&mut self, consumed: usize, len: usize) -> OwnedChunk {
+        let chunk = OwnedChunk::new(self.buffer[consumed..consumed + len].to_vec(), self.offset);
+        self.offset += len;
+        self.prev_len = Some(len);
+        chunk
+    }
+}
+
+impl Default for StreamingChunker {
+    fn default() -> Self {
+        Self::new(SeqChunking::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDataGenerator;
+
+    fn feed(streaming: &mut StreamingChunker, data: &[u8], split_points: &[usize]) -> Vec<OwnedChunk> {
+        let mut chunks = Vec::new();
+        let mut last = 0;
+        for &point in split_points {
+            chunks.extend(streaming.push(&data[last..point]));
+            last = point;
+        }
+        chunks.extend(streaming.push(&data[last..]));
+        chunks.extend(streaming.finish());
+        chunks
+    }
+
+    #[test]
+    fn test_streaming_matches_chunk_all() {
+        let data = TestDataGenerator::generate_pseudo_random(500_000, 21);
+        let chunker = SeqChunking::new();
+        let expected: Vec<OwnedChunk> = chunker.chunk_all(&data).map(OwnedChunk::from).collect();
+
+        let mut state: u64 = 0x5eed;
+        for _ in 0..5 {
+            let mut split_points = Vec::new();
+            let mut pos = 0;
+            loop {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                pos += (state >> 33) as usize % 40_000;
+                if pos >= data.len() {
+                    break;
+                }
+                split_points.push(pos);
+            }
+
+            let mut streaming = StreamingChunker::new(chunker.clone());
+            assert_eq!(feed(&mut streaming, &data, &split_points), expected);
+        }
+    }
+
+    #[test]
+    fn test_streaming_single_bytes() {
+        let data = TestDataGenerator::generate_mixed_patterns(50_000);
+        let config = ChunkingConfig::builder()
+            .fast_first_chunk(true)
+            .build()
+            .unwrap();
+        let chunker = SeqChunking::from_config(config.clone());
+        let expected: Vec<OwnedChunk> = chunker.chunk_all(&data).map(OwnedChunk::from).collect();
+
+        let mut streaming = StreamingChunker::from_config(config);
+        let split_points: Vec<usize> = (1..data.len()).collect();
+        assert_eq!(feed(&mut streaming, &data, &split_points), expected);
+    }
+
+    #[test]
+    fn test_streaming_full_remaining() {
+        let mut data = TestDataGenerator::generate_pseudo_random(30_000, 3);
+        data.extend(core::iter::repeat_n(7u8, 60_000));
+        let config = ChunkingConfig::builder()
+            .fallback(crate::FallbackPolicy::FullRemaining)
+            .build()
+            .unwrap();
+        let chunker = SeqChunking::from_config(config.clone());
+        let expected: Vec<OwnedChunk> = chunker.chunk_all(&data).map(OwnedChunk::from).collect();
+        assert!(expected.last().unwrap().len > crate::DEFAULT_MAX_BLOCK_SIZE as usize);
+
+        let mut streaming = StreamingChunker::from_config(config);
+        let split_points: Vec<usize> = (1..data.len()).step_by(1000).collect();
+        assert_eq!(feed(&mut streaming, &data, &split_points), expected);
+    }
+
+    #[test]
+    fn test_streaming_absolute_offsets_across_pushes() {
+        let data = TestDataGenerator::generate_pseudo_random(100_000, 22);
+        let max = crate::DEFAULT_MAX_BLOCK_SIZE as usize;
+        let mut streaming = StreamingChunker::default();
+
+        // The first push commits one chunk and leaves the rest buffered
+        let first = streaming.push(&data[..max + 100]);
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].start, 0);
+        let buffered = streaming.buffered_len();
+        assert_eq!(first[0].end() + buffered, max + 100);
+
+        let second = streaming.push(&data[max + 100..]);
+        assert_eq!(second[0].start, first[0].end());
+        assert_eq!(second[0].data, &data[second[0].range()]);
+
+        let mut chunks = first;
+        chunks.extend(second);
+        chunks.extend(streaming.finish());
+        for pair in chunks.windows(2) {
+            assert_eq!(pair[1].start, pair[0].end());
+        }
+        assert_eq!(chunks.last().unwrap().end(), data.len());
+    }
+
+    #[test]
+    fn test_streaming_max_size_ratio() {
+        let mut data = TestDataGenerator::generate_pseudo_random(100_000, 23);
+        data.extend(core::iter::repeat_n(9u8, 50_000));
+        data.extend(TestDataGenerator::generate_pseudo_random(100_000, 24));
+        let config = ChunkingConfig::builder().max_size_ratio(Some(1.5)).build().unwrap();
+        let expected: Vec<OwnedChunk> =
+            SeqChunking::from_config(config.clone()).chunk_all(&data).map(OwnedChunk::from).collect();
+
+        let mut streaming = StreamingChunker::from_config(config);
+        let split_points: Vec<usize> = (1..data.len()).step_by(3001).collect();
+        assert_eq!(feed(&mut streaming, &data, &split_points), expected);
+    }
+
+    #[test]
+    fn test_streaming_finish_resets() {
+        let mut streaming = StreamingChunker::default();
+        assert!(streaming.push(b"short").is_empty());
+        assert_eq!(streaming.buffered_len(), 5);
+
+        let tail = streaming.finish();
+        assert_eq!(tail.len(), 1);
+        assert_eq!(tail[0].data, b"short");
+        assert_eq!(streaming.position(), 0);
+        assert!(streaming.finish().is_empty());
+    }
+}
@@ -1,22 +1,35 @@
 //! Utility functions for the chunking library.
-
-use crate::{Chunk, ChunkingError, Result};
+//!
+//! [`FileUtils`] and [`PerfUtils`] require the `std` feature.
+
+use crate::{Chunk, ChunkHasher, ChunkingError, OwnedChunk, Result, SeqChunking};
+use alloc::collections::BTreeSet;
+#[cfg(feature = "serde")]
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::fmt;
+#[cfg(feature = "std")]
 use std::fs::File;
+#[cfg(feature = "std")]
 use std::io::{BufReader, BufWriter, Read, Write};
-use std::path::Path;
+#[cfg(feature = "std")]
+use std::path::{Path, PathBuf};
 
 /// Utility functions for file operations
+#[cfg(feature = "std")]
 pub struct FileUtils;
 
+#[cfg(feature = "std")]
 impl FileUtils {
     /// Read a file and return its contents as a Vec<u8>
     pub fn read_file<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
         let mut file = File::open(path.as_ref())
-            .map_err(|e| ChunkingError::io_error(format!("Failed to open file: {}", e)))?;
+            .map_err(|e| ChunkingError::io("Failed to open file", e))?;
 
         let mut buffer = Vec::new();
         file.read_to_end(&mut buffer)
-            .map_err(|e| ChunkingError::io_error(format!("Failed to read file: {}", e)))?;
+            .map_err(|e| ChunkingError::io("Failed to read file", e))?;
 
         Ok(buffer)
     }
@@ -24,13 +37,13 @@ impl FileUtils {
     /// Write data to a file
     pub fn write_file<P: AsRef<Path>>(path: P, data: &[u8]) -> Result<()> {
         let mut file = File::create(path.as_ref())
-            .map_err(|e| ChunkingError::io_error(format!("Failed to create file: {}", e)))?;
+            .map_err(|e| ChunkingError::io("Failed to create file", e))?;
 
         file.write_all(data)
-            .map_err(|e| ChunkingError::io_error(format!("Failed to write file: {}", e)))?;
+            .map_err(|e| ChunkingError::io("Failed to write file", e))?;
 
         file.flush()
-            .map_err(|e| ChunkingError::io_error(format!("Failed to flush file: {}", e)))?;
+            .map_err(|e| ChunkingError::io("Failed to flush file", e))?;
 
         Ok(())
     }
@@ -39,33 +52,246 @@ impl FileUtils {
     pub fn write_chunks_to_file<P: AsRef<Path>>(path: P, chunks: &[Chunk<'_>]) -> Result<()> {
         let mut file = BufWriter::new(
             File::create(path.as_ref())
-                .map_err(|e| ChunkingError::io_error(format!("Failed to create file: {}", e)))?,
+                .map_err(|e| ChunkingError::io("Failed to create file", e))?,
         );
 
         for chunk in chunks {
             file.write_all(chunk.data)
-                .map_err(|e| ChunkingError::io_error(format!("Failed to write chunk: {}", e)))?;
+                .map_err(|e| ChunkingError::io("Failed to write chunk", e))?;
         }
 
         file.flush()
-            .map_err(|e| ChunkingError::io_error(format!("Failed to flush file: {}", e)))?;
+            .map_err(|e| ChunkingError::io("Failed to flush file", e))?;
 
         Ok(())
     }
 
+    /// Write each chunk to its own file named `{prefix}{index:06}.chunk` in `dir`
+    ///
+    /// The directory is created if missing. If any write fails, the files
+    /// written so far are removed and the error names the failing file.
+    pub fn write_chunks_as_files<P: AsRef<Path>>(
+        dir: P,
+        chunks: &[Chunk<'_>],
+        prefix: &str,
+    ) -> Result<Vec<PathBuf>> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)
+            .map_err(|e| ChunkingError::io("Failed to create directory", e))?;
+
+        let mut paths = Vec::with_capacity(chunks.len());
+        for (index, chunk) in chunks.iter().enumerate() {
+            let path = dir.join(format!("{}{:06}.chunk", prefix, index));
+            let written = File::create(&path).and_then(|mut file| file.write_all(chunk.data));
+            if let Err(e) = written {
+                // Don't leave a partial set of chunk files behind
+                for written in paths.iter().chain(core::iter::once(&path)) {
+                    let _ = std::fs::remove_file(written);
+                }
+                return Err(ChunkingError::io(format!("Failed to write {}", path.display()), e));
+            }
+            paths.push(path);
+        }
+
+        Ok(paths)
+    }
+
     /// Read a file with buffered I/O for better performance on large files
     pub fn read_file_buffered<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
         let file = File::open(path.as_ref())
-            .map_err(|e| ChunkingError::io_error(format!("Failed to open file: {}", e)))?;
+            .map_err(|e| ChunkingError::io("Failed to open file", e))?;
 
         let mut reader = BufReader::new(file);
         let mut buffer = Vec::new();
         reader
             .read_to_end(&mut buffer)
-            .map_err(|e| ChunkingError::io_error(format!("Failed to read file: {}", e)))?;
+            .map_err(|e| ChunkingError::io("Failed to read file", e))?;
 
         Ok(buffer)
     }
+
+    /// Write the chunk boundaries (end offsets) as LEB128 varints of the gaps between them
+    ///
+    /// Each gap is a chunk length for contiguous chunks, so typical boundaries
+    /// take 2 or 3 bytes instead of 8. The first gap is measured from offset 0.
+    /// Chunks must be in order with increasing ends.
+    pub fn write_boundaries_varint<P: AsRef<Path>>(path: P, chunks: &[Chunk<'_>]) -> Result<()> {
+        let mut encoded = Vec::new();
+        let mut previous = 0;
+        for chunk in chunks {
+            let end = chunk.end();
+            if end <= previous {
+                return Err(ChunkingError::invalid_input(format!(
+                    "chunk boundary {} does not follow {}",
+                    end, previous
+                )));
+            }
+            let mut gap = (end - previous) as u64;
+            loop {
+                let byte = (gap & 0x7f) as u8;
+                gap >>= 7;
+                if gap == 0 {
+                    encoded.push(byte);
+                    break;
+                }
+                encoded.push(byte | 0x80);
+            }
+            previous = end;
+        }
+
+        Self::write_file(path, &encoded)
+    }
+
+    /// Read boundaries written by [`write_boundaries_varint`](Self::write_boundaries_varint)
+    pub fn read_boundaries_varint<P: AsRef<Path>>(path: P) -> Result<Vec<usize>> {
+        let encoded = Self::read_file(path)?;
+        let mut boundaries = Vec::new();
+        let mut position: u64 = 0;
+        let (mut gap, mut shift) = (0u64, 0);
+        for &byte in &encoded {
+            if shift >= 64 || (shift == 63 && byte > 1) {
+                return Err(ChunkingError::invalid_input("boundary varint overflows u64"));
+            }
+            gap |= ((byte & 0x7f) as u64) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                position = position
+                    .checked_add(gap)
+                    .filter(|&end| end <= usize::MAX as u64)
+                    .ok_or_else(|| ChunkingError::invalid_input("boundary offset overflows usize"))?;
+                boundaries.push(position as usize);
+                (gap, shift) = (0, 0);
+            }
+        }
+        if shift != 0 {
+            return Err(ChunkingError::invalid_input("truncated boundary varint"));
+        }
+
+        Ok(boundaries)
+    }
+
+    /// Chunk a file through a read-only memory map
+    ///
+    /// The file is never read into memory as a whole, so this works for files
+    /// larger than the available RAM. Zero-length files yield no chunks.
+    #[cfg(feature = "mmap")]
+    pub fn chunk_file_mmap<P: AsRef<Path>>(path: P, chunker: &SeqChunking) -> Result<Vec<OwnedChunk>> {
+        let file = File::open(path.as_ref())
+            .map_err(|e| ChunkingError::io("Failed to open file", e))?;
+
+        let len = file
+            .metadata()
+            .map_err(|e| ChunkingError::io("Failed to read file metadata", e))?
+            .len();
+        if len == 0 {
+            return Ok(Vec::new());
+        }
+
+        // SAFETY: the map is read-only; the caller must ensure the file is not
+        // truncated or modified by another process while it is being chunked.
+        let map = unsafe { memmap2::Mmap::map(&file) }
+            .map_err(|e| ChunkingError::io("Failed to map file", e))?;
+
+        Ok(chunker.chunk_all_owned(&map))
+    }
+
+    /// Write a JSON manifest listing the start, length and ID of every chunk
+    ///
+    /// IDs are computed with `hasher` and written as lowercase hex. Together
+    /// with the original file, the manifest locates every chunk without
+    /// running the chunker again.
+    #[cfg(feature = "serde")]
+    pub fn write_manifest<P: AsRef<Path>, H: ChunkHasher>(path: P, chunks: &[Chunk<'_>], hasher: H) -> Result<()> {
+        let entries: Vec<ChunkEntry> = chunks
+            .iter()
+            .map(|chunk| ChunkEntry {
+                start: chunk.start,
+                len: chunk.len,
+                hash: hasher.hash(chunk.data).iter().map(|byte| format!("{:02x}", byte)).collect(),
+            })
+            .collect();
+
+        let mut file = BufWriter::new(
+            File::create(path.as_ref())
+                .map_err(|e| ChunkingError::io("Failed to create manifest", e))?,
+        );
+        serde_json::to_writer(&mut file, &entries)
+            .map_err(|e| ChunkingError::io("Failed to write manifest", e.into()))?;
+        file.flush()
+            .map_err(|e| ChunkingError::io("Failed to flush manifest", e))?;
+
+        Ok(())
+    }
+
+    /// Read a manifest written by [`write_manifest`](Self::write_manifest)
+    #[cfg(feature = "serde")]
+    pub fn read_manifest<P: AsRef<Path>>(path: P) -> Result<Vec<ChunkEntry>> {
+        let file = File::open(path.as_ref())
+            .map_err(|e| ChunkingError::io("Failed to open manifest", e))?;
+
+        serde_json::from_reader(BufReader::new(file))
+            .map_err(|e| ChunkingError::invalid_input(format!("Failed to parse manifest: {}", e)))
+    }
+}
+
+/// One chunk of a manifest, see [`FileUtils::write_manifest`]
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ChunkEntry {
+    /// Offset of the chunk in the file
+    pub start: usize,
+    /// Length of the chunk in bytes
+    pub len: usize,
+    /// Chunk ID as lowercase hex
+    pub hash: String,
+}
+
+/// Concatenate owned chunks back into the original data
+pub fn reconstruct(chunks: &[OwnedChunk]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(chunks.iter().map(|c| c.len).sum());
+    for chunk in chunks {
+        data.extend_from_slice(&chunk.data);
+    }
+    data
+}
+
+/// Concatenate borrowed chunks back into the original data
+pub fn reconstruct_chunks(chunks: &[Chunk<'_>]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(chunks.iter().map(|c| c.len).sum());
+    for chunk in chunks {
+        data.extend_from_slice(chunk.data);
+    }
+    data
+}
+
+/// A single problem found by [`ValidationUtils::collect_coverage_errors`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CoverageError {
+    /// Chunk `at` starts after the end of the previous chunk
+    Gap { at: usize, expected: usize, found: usize },
+    /// Chunk `at` starts before the end of the previous chunk
+    Overlap { at: usize, expected: usize, found: usize },
+    /// Chunk `index` has no data
+    Empty { index: usize },
+    /// The chunks end at `found` instead of the data length `expected`
+    LengthMismatch { expected: usize, found: usize },
+}
+
+impl fmt::Display for CoverageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoverageError::Gap { at, expected, found } => {
+                write!(f, "gap before chunk {}: starts at {} but expected {}", at, found, expected)
+            }
+            CoverageError::Overlap { at, expected, found } => {
+                write!(f, "chunk {} overlaps its predecessor: starts at {} but expected {}", at, found, expected)
+            }
+            CoverageError::Empty { index } => write!(f, "chunk {} is empty", index),
+            CoverageError::LengthMismatch { expected, found } => {
+                write!(f, "chunks end at {} but data length is {}", found, expected)
+            }
+        }
+    }
 }
 
 /// Utility functions for data validation and verification
@@ -74,17 +300,16 @@ pub struct ValidationUtils;
 impl ValidationUtils {
     /// Verify that chunks can be reconstructed to match original data
     pub fn verify_chunks(original: &[u8], chunks: &[Chunk<'_>]) -> Result<bool> {
-        let reconstructed: Vec<u8> = chunks
-            .iter()
-            .flat_map(|chunk| chunk.data.iter())
-            .copied()
-            .collect();
-
-        Ok(reconstructed == original)
+        Ok(reconstruct_chunks(chunks) == original)
     }
 
     /// Check if chunks are contiguous and cover the entire data
     pub fn validate_chunk_coverage(data_len: usize, chunks: &[Chunk<'_>]) -> Result<()> {
+        Self::validate_chunk_coverage_from(0, data_len, chunks)
+    }
+
+    /// Check if chunks are contiguous and cover `data_len` bytes starting at `base_offset`
+    pub fn validate_chunk_coverage_from(base_offset: usize, data_len: usize, chunks: &[Chunk<'_>]) -> Result<()> {
         if chunks.is_empty() {
             if data_len == 0 {
                 return Ok(());
@@ -95,7 +320,7 @@ impl ValidationUtils {
             }
         }
 
-        let mut expected_start = 0;
+        let mut expected_start = base_offset;
 
         for (i, chunk) in chunks.iter().enumerate() {
             if chunk.start != expected_start {
@@ -115,15 +340,177 @@ impl ValidationUtils {
             expected_start = chunk.end();
         }
 
-        if expected_start != data_len {
+        if expected_start != base_offset + data_len {
             return Err(ChunkingError::processing_error(format!(
-                "Chunks end at {} but data length is {}",
-                expected_start, data_len
+                "Chunks end at {} but data ends at {}",
+                expected_start,
+                base_offset + data_len
             )));
         }
 
         Ok(())
     }
+
+    /// Report every coverage problem instead of stopping at the first
+    ///
+    /// Chunks are checked in order against the end of their predecessor, so a
+    /// misplaced chunk is reported as a gap or overlap and checking continues
+    /// from its end.
+    pub fn collect_coverage_errors(data_len: usize, chunks: &[Chunk<'_>]) -> Vec<CoverageError> {
+        let mut errors = Vec::new();
+        let mut expected = 0;
+
+        for (index, chunk) in chunks.iter().enumerate() {
+            if chunk.start > expected {
+                errors.push(CoverageError::Gap { at: index, expected, found: chunk.start });
+            } else if chunk.start < expected {
+                errors.push(CoverageError::Overlap { at: index, expected, found: chunk.start });
+            }
+
+            if chunk.is_empty() {
+                errors.push(CoverageError::Empty { index });
+            }
+
+            expected = chunk.end();
+        }
+
+        if expected != data_len {
+            errors.push(CoverageError::LengthMismatch { expected: data_len, found: expected });
+        }
+
+        errors
+    }
+
+    /// Find pairs of chunk indices whose ranges overlap
+    ///
+    /// Empty chunks never overlap. Each pair is reported once as `(i, j)`
+    /// with `i < j`, sorted.
+    pub fn find_overlaps(chunks: &[Chunk<'_>]) -> Vec<(usize, usize)> {
+        let mut order: Vec<usize> = (0..chunks.len()).filter(|&i| !chunks[i].is_empty()).collect();
+        order.sort_by_key(|&i| chunks[i].start);
+
+        let mut overlaps = Vec::new();
+        for (pos, &i) in order.iter().enumerate() {
+            let end = chunks[i].end();
+            for &j in order[pos + 1..].iter().take_while(|&&j| chunks[j].start < end) {
+                overlaps.push((i.min(j), i.max(j)));
+            }
+        }
+
+        overlaps.sort_unstable();
+        overlaps
+    }
+
+    /// Re-cut contiguous chunks so every length falls within `lo..=hi`
+    ///
+    /// Chunks longer than `hi` are split every `hi` bytes, and a piece shorter
+    /// than `lo` (including a short tail) is merged into the chunk before it.
+    /// A merge that grows past `hi` is split again, leaving `lo` bytes in the
+    /// second part. Only data shorter than `lo` as a whole yields a shorter
+    /// chunk. Requires `0 < lo` and `2 * lo <= hi + 1`, so that such a split
+    /// always fits; the chunks must be contiguous.
+    pub fn enforce_size_bounds(chunks: &[Chunk<'_>], lo: usize, hi: usize) -> Result<Vec<OwnedChunk>> {
+        if lo == 0 || lo.saturating_mul(2) > hi.saturating_add(1) {
+            return Err(ChunkingError::invalid_input(format!(
+                "size bounds {}..={} need 0 < lo and 2 * lo <= hi + 1",
+                lo, hi
+            )));
+        }
+        let Some(first) = chunks.first() else {
+            return Ok(Vec::new());
+        };
+        let base = first.start;
+        Self::validate_chunk_coverage_from(base, chunks.iter().map(|chunk| chunk.len).sum(), chunks)?;
+
+        let mut lens: Vec<usize> = Vec::new();
+        let mut push = |len: usize| match lens.last_mut() {
+            Some(last) if len < lo || *last < lo => {
+                let merged = *last + len;
+                if merged > hi {
+                    *last = merged - lo;
+                    lens.push(lo);
+                } else {
+                    *last = merged;
+                }
+            }
+            _ => lens.push(len),
+        };
+        for chunk in chunks {
+            let mut left = chunk.len;
+            while left > hi {
+                push(hi);
+                left -= hi;
+            }
+            push(left);
+        }
+
+        let data = reconstruct_chunks(chunks);
+        let mut start = 0;
+        Ok(lens
+            .into_iter()
+            .map(|len| {
+                let chunk = OwnedChunk::new(data[start..start + len].to_vec(), base + start);
+                start += len;
+                chunk
+            })
+            .collect())
+    }
+
+    /// Fraction of chunk boundaries after an edit that survive it
+    ///
+    /// The edit region is located from the common prefix of both inputs.
+    /// Every boundary of `original` past the edit is shifted by the length
+    /// difference and looked up among the boundaries of `edited`. The final
+    /// boundary (end of data) is not counted. Returns 1.0 when there are no
+    /// boundaries past the edit.
+    pub fn boundary_stability(original: &[u8], edited: &[u8], chunker: &SeqChunking) -> f64 {
+        let edit_point = original
+            .iter()
+            .zip(edited)
+            .take_while(|(a, b)| a == b)
+            .count();
+        let shift = edited.len() as i64 - original.len() as i64;
+
+        let mut original_cuts = chunker.chunk_offsets(original);
+        let mut edited_cuts = chunker.chunk_offsets(edited);
+        original_cuts.pop();
+        edited_cuts.pop();
+
+        let candidates: Vec<usize> = original_cuts
+            .into_iter()
+            .filter(|&cut| cut > edit_point)
+            .collect();
+        if candidates.is_empty() {
+            return 1.0;
+        }
+
+        let shared = candidates
+            .iter()
+            .filter(|&&cut| {
+                let shifted = cut as i64 + shift;
+                shifted >= 0 && edited_cuts.binary_search(&(shifted as usize)).is_ok()
+            })
+            .count();
+
+        shared as f64 / candidates.len() as f64
+    }
+
+    /// Jaccard similarity of the chunk IDs of two inputs
+    ///
+    /// Both inputs are chunked with `chunker` and every chunk is hashed with
+    /// `hasher`. Returns the number of distinct IDs found in both inputs divided
+    /// by the number found in either, which measures how much of one version
+    /// dedups against the other. Returns 1.0 when both inputs are empty.
+    pub fn shared_chunk_ratio<H: ChunkHasher>(a: &[u8], b: &[u8], chunker: &SeqChunking, hasher: H) -> f64 {
+        let ids = |data| -> BTreeSet<Vec<u8>> { chunker.chunk_all(data).map(|chunk| hasher.hash(chunk.data)).collect() };
+        let (a_ids, b_ids) = (ids(a), ids(b));
+
+        let union = a_ids.union(&b_ids).count();
+        if union == 0 {
+            return 1.0;
+        }
+        a_ids.intersection(&b_ids).count() as f64 / union as f64
+    }
 }
 
 /// Utility functions for generating test data
@@ -205,11 +592,36 @@ impl TestDataGenerator {
 
         data
     }
+
+    /// Generate data holding `dup_copies` exact copies of a random base region
+    ///
+    /// The layout is the base region of `unique_size` bytes, then for each copy
+    /// a unique filler of `unique_size / 4` bytes followed by the copy. Ideal
+    /// deduplication therefore saves
+    /// `dup_copies * unique_size / (unique_size + dup_copies * (unique_size + unique_size / 4))`
+    /// of the bytes; a content-defined chunker loses a chunk or two at each
+    /// copy's edges.
+    pub fn generate_with_duplicates(unique_size: usize, dup_copies: usize, seed: u64) -> Vec<u8> {
+        let base = Self::generate_pseudo_random(unique_size, seed);
+        let filler_size = unique_size / 4;
+        let mut data = Vec::with_capacity(unique_size + dup_copies * (unique_size + filler_size));
+        data.extend_from_slice(&base);
+
+        for copy in 0..dup_copies {
+            let filler_seed = seed.wrapping_add(1 + copy as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+            data.extend(Self::generate_pseudo_random(filler_size, filler_seed));
+            data.extend_from_slice(&base);
+        }
+
+        data
+    }
 }
 
 /// Performance measurement utilities
+#[cfg(feature = "std")]
 pub struct PerfUtils;
 
+#[cfg(feature = "std")]
 impl PerfUtils {
     /// Measure the time taken to execute a closure
     pub fn measure_time<F, R>(f: F) -> (R, std::time::Duration)
@@ -242,10 +654,12 @@ impl PerfUtils {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::SeqChunking;
+    #[cfg(feature = "std")]
     use std::io::Write;
+    #[cfg(feature = "std")]
     use tempfile::NamedTempFile;
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_file_read_write() {
         let test_data = b"Hello, World! This is test data.";
@@ -258,6 +672,78 @@ mod tests {
         assert_eq!(read_data, test_data);
     }
 
+    #[cfg(all(feature = "serde", feature = "hashing"))]
+    #[test]
+    fn test_manifest_round_trip() {
+        use crate::hasher::Sha256Hasher;
+
+        let data = TestDataGenerator::generate_mixed_patterns(200_000);
+        let chunker = SeqChunking::new();
+        let chunks: Vec<_> = chunker.chunk_all(&data).collect();
+        let temp_file = NamedTempFile::new().unwrap();
+
+        FileUtils::write_manifest(temp_file.path(), &chunks, Sha256Hasher).unwrap();
+        let entries = FileUtils::read_manifest(temp_file.path()).unwrap();
+
+        assert_eq!(entries.len(), chunks.len());
+        for (entry, chunk) in entries.iter().zip(&chunks) {
+            assert_eq!((entry.start, entry.len), (chunk.start, chunk.len));
+            assert_eq!(&data[entry.start..entry.start + entry.len], chunk.data);
+            assert_eq!(entry.hash.len(), 64);
+            assert!(entry.hash.starts_with(&format!("{:02x}", Sha256Hasher.hash(chunk.data)[0])));
+        }
+
+        std::fs::write(temp_file.path(), b"not json").unwrap();
+        assert!(FileUtils::read_manifest(temp_file.path()).is_err());
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn test_chunk_file_mmap() {
+        let data = TestDataGenerator::generate_pseudo_random(200_000, 4);
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(&data).unwrap();
+        temp_file.flush().unwrap();
+
+        let chunker = SeqChunking::new();
+        let chunks = FileUtils::chunk_file_mmap(temp_file.path(), &chunker).unwrap();
+        let expected: Vec<OwnedChunk> = chunker.chunk_all(&data).map(OwnedChunk::from).collect();
+        assert_eq!(chunks, expected);
+
+        let empty = NamedTempFile::new().unwrap();
+        assert!(FileUtils::chunk_file_mmap(empty.path(), &chunker).unwrap().is_empty());
+
+        let missing = FileUtils::chunk_file_mmap("/nonexistent/seqcdc.dat", &chunker);
+        assert!(matches!(missing, Err(ChunkingError::Io { .. })));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_boundaries_varint_round_trip() {
+        let data = TestDataGenerator::generate_pseudo_random(1 << 20, 44);
+        let chunker = SeqChunking::new();
+        let chunks: Vec<_> = chunker.chunk_all(&data).collect();
+        let temp_file = NamedTempFile::new().unwrap();
+
+        FileUtils::write_boundaries_varint(temp_file.path(), &chunks).unwrap();
+        assert_eq!(FileUtils::read_boundaries_varint(temp_file.path()).unwrap(), chunks.iter().map(|c| c.end()).collect::<Vec<_>>());
+
+        // Chunk lengths below 2^21 need at most 3 bytes instead of 8
+        let varint_size = std::fs::metadata(temp_file.path()).unwrap().len() as usize;
+        assert!(varint_size <= 3 * chunks.len(), "{} bytes", varint_size);
+        assert!(varint_size < 8 * chunks.len());
+
+        FileUtils::write_boundaries_varint(temp_file.path(), &[]).unwrap();
+        assert!(FileUtils::read_boundaries_varint(temp_file.path()).unwrap().is_empty());
+
+        // A set continuation bit on the last byte is a truncated stream
+        FileUtils::write_file(temp_file.path(), &[0x80, 0x80]).unwrap();
+        assert!(FileUtils::read_boundaries_varint(temp_file.path()).is_err());
+
+        let reversed = [chunks[1].clone(), chunks[0].clone()];
+        assert!(FileUtils::write_boundaries_varint(temp_file.path(), &reversed).is_err());
+    }
+
     #[test]
     fn test_chunk_verification() {
         let chunker = SeqChunking::new();
@@ -278,6 +764,140 @@ mod tests {
         ValidationUtils::validate_chunk_coverage(data.len(), &chunks).unwrap();
     }
 
+    #[test]
+    fn test_reconstruct() {
+        let chunker = SeqChunking::new();
+        let inputs = [
+            Vec::new(),
+            b"short".to_vec(),
+            TestDataGenerator::generate_mixed_patterns(50_000),
+            TestDataGenerator::generate_pseudo_random(200_000, 5),
+        ];
+
+        for data in &inputs {
+            assert_eq!(&reconstruct(&chunker.chunk_all_owned(data)), data);
+            let chunks: Vec<_> = chunker.chunk_all(data).collect();
+            assert_eq!(&reconstruct_chunks(&chunks), data);
+        }
+    }
+
+    #[test]
+    fn test_collect_coverage_errors() {
+        let data = [0u8; 100];
+        let chunker = SeqChunking::new();
+        let chunks: Vec<_> = chunker.chunk_all(&data).collect();
+        assert!(ValidationUtils::collect_coverage_errors(data.len(), &chunks).is_empty());
+
+        let chunks = vec![
+            Chunk::new(&data[0..30], 0, 30),
+            Chunk::new(&data[40..60], 40, 20),
+            Chunk::new(&data[50..50], 50, 0),
+            Chunk::new(&data[50..90], 50, 40),
+        ];
+        let errors = ValidationUtils::collect_coverage_errors(data.len(), &chunks);
+        assert_eq!(
+            errors,
+            vec![
+                CoverageError::Gap { at: 1, expected: 30, found: 40 },
+                CoverageError::Overlap { at: 2, expected: 60, found: 50 },
+                CoverageError::Empty { index: 2 },
+                CoverageError::LengthMismatch { expected: 100, found: 90 },
+            ]
+        );
+        assert_eq!(errors[0].to_string(), "gap before chunk 1: starts at 40 but expected 30");
+    }
+
+    #[test]
+    fn test_find_overlaps() {
+        let data = [0u8; 100];
+        let chunker = SeqChunking::new();
+        let chunks: Vec<_> = chunker.chunk_all(&data).collect();
+        assert!(ValidationUtils::find_overlaps(&chunks).is_empty());
+
+        let chunks = vec![
+            Chunk::new(&data[0..40], 0, 40),
+            Chunk::new(&data[40..70], 40, 30),
+            Chunk::new(&data[60..100], 60, 40),
+        ];
+        assert_eq!(ValidationUtils::find_overlaps(&chunks), vec![(1, 2)]);
+    }
+
+    #[test]
+    fn test_enforce_size_bounds() {
+        let data = TestDataGenerator::generate_pseudo_random(10_000, 33);
+        let check = |chunks: &[Chunk<'_>], lo: usize, hi: usize| {
+            let bounded = ValidationUtils::enforce_size_bounds(chunks, lo, hi).unwrap();
+            assert!(bounded.iter().all(|chunk| (lo..=hi).contains(&chunk.len)));
+            assert_eq!(bounded[0].start, chunks[0].start);
+            assert!(bounded.windows(2).all(|pair| pair[0].start + pair[0].len == pair[1].start));
+            assert_eq!(reconstruct(&bounded), reconstruct_chunks(chunks));
+            bounded
+        };
+
+        // A tiny tail is merged into the chunk before it
+        let chunks = vec![
+            Chunk::new(&data[0..3000], 0, 3000),
+            Chunk::new(&data[3000..6000], 3000, 3000),
+            Chunk::new(&data[6000..6010], 6000, 10),
+        ];
+        let bounded = check(&chunks, 1000, 4000);
+        assert_eq!(bounded.iter().map(|chunk| chunk.len).collect::<Vec<_>>(), [3000, 3010]);
+
+        // An oversized forced chunk is split, and its short remainder rebalanced
+        let chunks = vec![
+            Chunk::new(&data[100..200], 100, 100),
+            Chunk::new(&data[200..9200], 200, 9000),
+            Chunk::new(&data[9200..9300], 9200, 100),
+        ];
+        check(&chunks, 1000, 4000);
+        check(&chunks, 2000, 3999);
+
+        let chunker = SeqChunking::new();
+        let chunks: Vec<_> = chunker.chunk_all(&data).collect();
+        check(&chunks, 1, 10_000);
+
+        assert!(ValidationUtils::enforce_size_bounds(&chunks, 0, 10).is_err());
+        assert!(ValidationUtils::enforce_size_bounds(&chunks, 10, 18).is_err());
+        assert!(ValidationUtils::enforce_size_bounds(&[], 10, 20).unwrap().is_empty());
+        let gap = [Chunk::new(&data[0..10], 0, 10), Chunk::new(&data[20..30], 20, 10)];
+        assert!(ValidationUtils::enforce_size_bounds(&gap, 1, 10).is_err());
+    }
+
+    #[test]
+    fn test_boundary_stability() {
+        let chunker = SeqChunking::new();
+        let original = TestDataGenerator::generate_pseudo_random(1 << 20, 17);
+
+        let mut edited = original.clone();
+        let mid = original.len() / 2;
+        edited.splice(mid..mid, [0xAB; 10]);
+
+        let stability = ValidationUtils::boundary_stability(&original, &edited, &chunker);
+        assert!(stability > 0.8, "stability {}", stability);
+        assert_eq!(ValidationUtils::boundary_stability(&original, &original, &chunker), 1.0);
+    }
+
+    #[cfg(feature = "hashing")]
+    #[test]
+    fn test_shared_chunk_ratio() {
+        use crate::hasher::Sha256Hasher;
+
+        let chunker = SeqChunking::new();
+        let original = TestDataGenerator::generate_pseudo_random(1 << 20, 36);
+
+        let mut edited = original.clone();
+        let mid = original.len() / 3;
+        edited.splice(mid..mid + 100, [0x5A; 40]);
+
+        let ratio = ValidationUtils::shared_chunk_ratio(&original, &edited, &chunker, Sha256Hasher);
+        assert!(ratio > 0.8 && ratio < 1.0, "ratio {}", ratio);
+        assert_eq!(ValidationUtils::shared_chunk_ratio(&original, &original, &chunker, Sha256Hasher), 1.0);
+        assert_eq!(ValidationUtils::shared_chunk_ratio(&[], &[], &chunker, Sha256Hasher), 1.0);
+
+        let unrelated = TestDataGenerator::generate_pseudo_random(1 << 20, 37);
+        assert!(ValidationUtils::shared_chunk_ratio(&original, &unrelated, &chunker, Sha256Hasher) < 0.1);
+    }
+
     #[test]
     fn test_test_data_generation() {
         let data = TestDataGenerator::generate_increasing_sequences(1000, 10, 5);
@@ -293,6 +913,7 @@ mod tests {
         assert_eq!(data.len(), 1000);
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_performance_utils() {
         let (result, duration) = PerfUtils::measure_time(|| {
@@ -308,6 +929,41 @@ mod tests {
         assert_eq!(throughput, 1.0);
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_write_chunks_as_files() {
+        let data = TestDataGenerator::generate_pseudo_random(50_000, 8);
+        let chunker = SeqChunking::new();
+        let chunks: Vec<_> = chunker.chunk_all(&data).collect();
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = temp_dir.path().join("chunks");
+        let paths = FileUtils::write_chunks_as_files(&dir, &chunks, "c").unwrap();
+
+        assert_eq!(paths.len(), chunks.len());
+        assert_eq!(paths[1], dir.join("c000001.chunk"));
+        for (path, chunk) in paths.iter().zip(&chunks) {
+            assert_eq!(FileUtils::read_file(path).unwrap(), chunk.data);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_write_chunks_as_files_cleans_up_on_error() {
+        let data = TestDataGenerator::generate_pseudo_random(50_000, 8);
+        let chunker = SeqChunking::new();
+        let chunks: Vec<_> = chunker.chunk_all(&data).collect();
+
+        // A directory in place of the second file makes its creation fail
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(temp_dir.path().join("c000001.chunk")).unwrap();
+
+        let err = FileUtils::write_chunks_as_files(temp_dir.path(), &chunks, "c").unwrap_err();
+        assert!(err.to_string().contains("c000001.chunk"));
+        assert!(!temp_dir.path().join("c000000.chunk").exists());
+    }
+
+    #[cfg(feature = "std")]
     #[test]
     fn test_write_chunks_to_file() {
         let chunker = SeqChunking::new();
@@ -320,4 +976,31 @@ mod tests {
         let reconstructed_data = FileUtils::read_file(temp_file.path()).unwrap();
         assert_eq!(reconstructed_data, original_data);
     }
+
+    #[test]
+    fn test_generate_with_duplicates_layout() {
+        let data = TestDataGenerator::generate_with_duplicates(1000, 3, 5);
+        assert_eq!(data.len(), 1000 + 3 * 1250);
+        let filler = |copy: usize| &data[copy * 1250 - 250..copy * 1250];
+        for copy in 1..=3 {
+            assert_eq!(data[copy * 1250..copy * 1250 + 1000], data[..1000]);
+        }
+        assert_ne!(filler(1), filler(2));
+        assert_ne!(filler(2), filler(3));
+        let no_copies = TestDataGenerator::generate_with_duplicates(1000, 0, 5);
+        assert_eq!(no_copies, TestDataGenerator::generate_pseudo_random(1000, 5));
+    }
+
+    #[cfg(all(feature = "hashing", feature = "std"))]
+    #[test]
+    fn test_generate_with_duplicates_dedup_ratio() {
+        let (unique, copies) = (256 * 1024, 3);
+        let data = TestDataGenerator::generate_with_duplicates(unique, copies, 6);
+        let expected = (copies * unique) as f64 / data.len() as f64;
+
+        let stats = crate::SeqChunking::new().dedup_stats(&data);
+        assert!(stats.dedup_ratio <= expected);
+        // Chunks straddling each copy's edges are lost to deduplication
+        assert!(stats.dedup_ratio > expected - 0.1, "{} vs {}", stats.dedup_ratio, expected);
+    }
 }
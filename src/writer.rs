@@ -0,0 +1,116 @@
+//! A `Write` adapter that chunks the bytes passing through it.
+
+use crate::chunker::{OwnedChunk, SeqChunking};
+use crate::streaming::StreamingChunker;
+use std::io::{self, Write};
+
+/// Writer that forwards bytes to an inner writer while chunking them
+///
+/// Every chunk is passed to the `on_chunk` callback as soon as its boundary is
+/// committed, with the boundaries of [`SeqChunking::chunk_all`] over the whole
+/// stream (see [`StreamingChunker`]). This lets data be piped through
+/// `std::io::copy` while recording an index of its chunks.
+///
+/// [`flush`](Write::flush) only flushes the inner writer: ending the chunk
+/// there would make boundaries depend on where callers flush. The tail chunk is
+/// emitted by [`finish`](Self::finish), or on drop if `finish` was not called.
+pub struct ChunkingWriter<W: Write, F: FnMut(&OwnedChunk)> {
+    inner: Option<W>,
+    streaming: StreamingChunker,
+    on_chunk: F,
+}
+
+impl<W: Write, F: FnMut(&OwnedChunk)> ChunkingWriter<W, F> {
+    /// Create a writer forwarding to `inner` and reporting chunks to `on_chunk`
+    pub fn new(inner: W, chunker: SeqChunking, on_chunk: F) -> Self {
+        Self {
+            inner: Some(inner),
+            streaming: StreamingChunker::new(chunker),
+            on_chunk,
+        }
+    }
+
+    /// Get a reference to the inner writer
+    pub fn get_ref(&self) -> &W {
+        self.inner.as_ref().expect("inner writer is only taken by finish")
+    }
+
+    /// Emit the tail chunks, flush and return the inner writer
+    pub fn finish(mut self) -> io::Result<W> {
+        self.emit_tail();
+        let mut inner = self.inner.take().expect("inner writer is only taken by finish");
+        inner.flush()?;
+        Ok(inner)
+    }
+
+    fn emit_tail(&mut self) {
+        for chunk in self.streaming.finish() {
+            (self.on_chunk)(&chunk);
+        }
+    }
+}
+
+impl<W: Write, F: FnMut(&OwnedChunk)> Write for ChunkingWriter<W, F> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let inner = self.inner.as_mut().expect("inner writer is only taken by finish");
+        // Only the bytes the inner writer accepted are chunked
+        let written = inner.write(buf)?;
+        for chunk in self.streaming.push(&buf[..written]) {
+            (self.on_chunk)(&chunk);
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.as_mut().expect("inner writer is only taken by finish").flush()
+    }
+}
+
+impl<W: Write, F: FnMut(&OwnedChunk)> Drop for ChunkingWriter<W, F> {
+    fn drop(&mut self) {
+        if let Some(mut inner) = self.inner.take() {
+            self.emit_tail();
+            // Errors cannot be reported from drop; call `finish` to see them
+            let _ = inner.flush();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TestDataGenerator;
+
+    #[test]
+    fn test_chunking_writer_matches_chunk_all() {
+        let data = TestDataGenerator::generate_mixed_patterns(300_000);
+        let chunker = SeqChunking::new();
+        let expected: Vec<OwnedChunk> = chunker.chunk_all_owned(&data);
+
+        let mut chunks = Vec::new();
+        let mut writer = ChunkingWriter::new(Vec::new(), chunker.clone(), |chunk: &OwnedChunk| chunks.push(chunk.clone()));
+        io::copy(&mut &data[..], &mut writer).unwrap();
+        // A flush mid-stream must not end a chunk
+        writer.flush().unwrap();
+        assert_eq!(writer.get_ref().len(), data.len());
+        let output = writer.finish().unwrap();
+
+        assert_eq!(output, data);
+        assert_eq!(chunks, expected);
+    }
+
+    #[test]
+    fn test_chunking_writer_drop_emits_tail() {
+        let data = TestDataGenerator::generate_pseudo_random(50_000, 41);
+        let chunker = SeqChunking::new();
+
+        let mut ends = Vec::new();
+        {
+            let mut writer = ChunkingWriter::new(io::sink(), chunker.clone(), |chunk: &OwnedChunk| ends.push(chunk.end()));
+            for piece in data.chunks(777) {
+                writer.write_all(piece).unwrap();
+            }
+        }
+        assert_eq!(ends, chunker.chunk_offsets(&data));
+    }
+}